@@ -1,5 +1,5 @@
 use proc_macro::{self, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{parse_macro_input, DeriveInput};
 
 fn add_trait_bounds(mut generics: syn::Generics) -> syn::Generics {
@@ -11,50 +11,16 @@ fn add_trait_bounds(mut generics: syn::Generics) -> syn::Generics {
     generics
 }
 
-use darling::FromDeriveInput;
-
-#[derive(FromDeriveInput, Debug)]
-#[darling(attributes(typenameable))]
-struct TypeNameArguments {
-    #[darling(default)]
-    type_name: Option<syn::Ident>,
-    #[darling(default)]
+/// Generates the code which constructs the TypeInfo, using the given generics
+fn type_info_body(
+    type_name: String,
     crate_name: Option<syn::Ident>,
-    #[darling(default)]
     crate_module: Option<String>,
-    #[darling(default)]
     crate_version: Option<String>,
-    #[darling(default)]
     rustc_version: Option<String>,
-    #[darling(default)]
     default_to_none: bool,
-}
-
-#[proc_macro_derive(TypeNameable, attributes(typenameable))]
-pub fn derive_type_name(tokens: TokenStream) -> TokenStream {
-    let derived = parse_macro_input!(tokens);
-    let TypeNameArguments {
-        type_name,
-        crate_name,
-        crate_module,
-        crate_version,
-        rustc_version,
-        default_to_none,
-    } = TypeNameArguments::from_derive_input(&derived).unwrap();
-
-    let DeriveInput {
-        ident,
-        attrs: _,
-        vis: _,
-        generics,
-        data: _,
-    } = derived;
-    let generics = add_trait_bounds(generics);
-    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-    let type_name = type_name
-        .map(|x| x.to_string())
-        .unwrap_or_else(|| ident.to_string());
+    generics: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
     let crate_name = if let Some(crate_name) = crate_name {
         quote!(Some(stringify!(#crate_name).to_owned()))
     } else if default_to_none {
@@ -104,12 +70,7 @@ pub fn derive_type_name(tokens: TokenStream) -> TokenStream {
             ::typenaming::rustc_version().expect("Failed to fetch rustc version")
         ))
     };
-    let generics = generics
-        .type_params()
-        .map(|x| x.ident.clone())
-        .collect::<Vec<_>>();
-    let generics = quote!(#(<#generics as ::typenaming::TypeNameable>::type_info()),*);
-    let body = quote! {
+    quote! {
         #module_path_import
         ::typenaming::TypeInfo::new(
             #type_name.to_owned(),
@@ -121,6 +82,184 @@ pub fn derive_type_name(tokens: TokenStream) -> TokenStream {
               #generics
             ]
         )
+    }
+}
+
+use darling::{FromDeriveInput, FromMeta};
+
+#[derive(FromDeriveInput, Debug)]
+#[darling(attributes(typenameable))]
+struct TypeNameArguments {
+    #[darling(default)]
+    type_name: Option<syn::Ident>,
+    #[darling(default)]
+    crate_name: Option<syn::Ident>,
+    #[darling(default)]
+    crate_module: Option<String>,
+    #[darling(default)]
+    crate_version: Option<String>,
+    #[darling(default)]
+    rustc_version: Option<String>,
+    #[darling(default)]
+    default_to_none: bool,
+    #[darling(default)]
+    fields: bool,
+}
+
+/// Collects the given type and all types contained in it, e.g. 'Vec<User>' and 'User' for 'Vec<User>'
+fn referenced_types(ty: &syn::Type, types: &mut Vec<syn::Type>) {
+    match ty {
+        syn::Type::Path(path) => {
+            types.push(ty.clone());
+            generic_types(path, types);
+        }
+        syn::Type::Array(array) => referenced_types(&array.elem, types),
+        syn::Type::Group(group) => referenced_types(&group.elem, types),
+        syn::Type::Paren(paren) => referenced_types(&paren.elem, types),
+        syn::Type::Ptr(pointer) => referenced_types(&pointer.elem, types),
+        // TypeNameable is implemented for references, but the referenced type may be unsized, e.g. 'str'
+        syn::Type::Reference(reference) => {
+            types.push(ty.clone());
+            match &*reference.elem {
+                syn::Type::Path(path) => generic_types(path, types),
+                elem => referenced_types(elem, types),
+            }
+        }
+        syn::Type::Slice(slice) => referenced_types(&slice.elem, types),
+        syn::Type::Tuple(tuple) => {
+            for ty in &tuple.elems {
+                referenced_types(ty, types);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collects all types contained in the generic arguments of the given path, e.g. 'User' for 'Vec<User>'
+fn generic_types(path: &syn::TypePath, types: &mut Vec<syn::Type>) {
+    if let Some(segment) = path.path.segments.last() {
+        if let syn::PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            for argument in &arguments.args {
+                if let syn::GenericArgument::Type(ty) = argument {
+                    referenced_types(ty, types);
+                }
+            }
+        }
+    }
+}
+
+/// Generates the code which constructs the Fields of a struct or of an enum variant
+fn fields_body(fields: &syn::Fields) -> proc_macro2::TokenStream {
+    let field = |field: &syn::Field| {
+        let name = match &field.ident {
+            Some(ident) => {
+                let name = ident.to_string();
+                let name = name.strip_prefix("r#").unwrap_or(&name);
+                quote!(Some(#name.to_owned()))
+            }
+            None => quote!(None),
+        };
+        let ty = &field.ty;
+        let mut references = Vec::new();
+        referenced_types(ty, &mut references);
+        quote! {
+            ::typenaming::Field::new(
+                #name,
+                <#ty as ::typenaming::TypeNameable>::type_info(),
+                vec![#(
+                    (|| (
+                        <#references as ::typenaming::TypeNameable>::type_info(),
+                        <#references as ::typenaming::TypeNameable>::type_shape(),
+                    )) as ::typenaming::TypeReference
+                ),*],
+            )
+        }
+    };
+    match fields {
+        syn::Fields::Named(fields) => {
+            let fields = fields.named.iter().map(field);
+            quote!(::typenaming::Fields::Named(vec![#(#fields),*]))
+        }
+        syn::Fields::Unnamed(fields) => {
+            let fields = fields.unnamed.iter().map(field);
+            quote!(::typenaming::Fields::Unnamed(vec![#(#fields),*]))
+        }
+        syn::Fields::Unit => quote!(::typenaming::Fields::Unit),
+    }
+}
+
+/// Generates the code which constructs the TypeShape, i.e. the fields of a struct or the variants of an enum
+fn type_shape_body(data: &syn::Data) -> proc_macro2::TokenStream {
+    match data {
+        syn::Data::Struct(data) => {
+            let fields = fields_body(&data.fields);
+            quote!(::typenaming::TypeShape::Struct(#fields))
+        }
+        syn::Data::Enum(data) => {
+            let variants = data.variants.iter().map(|variant| {
+                let name = variant.ident.to_string();
+                let name = name.strip_prefix("r#").unwrap_or(&name);
+                let fields = fields_body(&variant.fields);
+                quote!(::typenaming::Variant::new(#name.to_owned(), #fields))
+            });
+            quote!(::typenaming::TypeShape::Enum(vec![#(#variants),*]))
+        }
+        syn::Data::Union(data) => {
+            syn::Error::new(data.union_token.span, "Fields of unions cannot be recorded")
+                .to_compile_error()
+        }
+    }
+}
+
+#[proc_macro_derive(TypeNameable, attributes(typenameable))]
+pub fn derive_type_name(tokens: TokenStream) -> TokenStream {
+    let derived = parse_macro_input!(tokens);
+    let TypeNameArguments {
+        type_name,
+        crate_name,
+        crate_module,
+        crate_version,
+        rustc_version,
+        default_to_none,
+        fields,
+    } = TypeNameArguments::from_derive_input(&derived).unwrap();
+
+    let DeriveInput {
+        ident,
+        attrs: _,
+        vis: _,
+        generics,
+        data,
+    } = derived;
+    let generics = add_trait_bounds(generics);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let type_name = type_name
+        .map(|x| x.to_string())
+        .unwrap_or_else(|| ident.to_string());
+    let generics = generics
+        .type_params()
+        .map(|x| x.ident.clone())
+        .collect::<Vec<_>>();
+    let generics = quote!(#(<#generics as ::typenaming::TypeNameable>::type_info()),*);
+    let body = type_info_body(
+        type_name,
+        crate_name,
+        crate_module,
+        crate_version,
+        rustc_version,
+        default_to_none,
+        generics,
+    );
+    let type_shape = if fields {
+        let shape = type_shape_body(&data);
+        quote! {
+            fn type_shape() -> Option<::typenaming::TypeShape> {
+                Some(#shape)
+            }
+        }
+    } else {
+        quote!()
     };
     quote! {
         #[automatically_derived]
@@ -128,6 +267,7 @@ pub fn derive_type_name(tokens: TokenStream) -> TokenStream {
             fn type_info() -> ::typenaming::TypeInfo {
                 #body
             }
+            #type_shape
         }
     }
     .into()
@@ -175,73 +315,20 @@ pub fn typequeryable(tokens: TokenStream) -> TokenStream {
     let type_name = type_name
         .map(|x| x.to_string())
         .unwrap_or_else(|| ident.to_string());
-    let crate_name = if let Some(crate_name) = crate_name {
-        quote!(Some(stringify!(#crate_name).to_owned()))
-    } else if default_to_none {
-        quote!(None)
-    } else {
-        quote!(Some(env!("CARGO_PKG_NAME").to_owned()))
-    };
-    let module_path_import;
-    let crate_module = if let Some(crate_module) = crate_module {
-        module_path_import = quote! {};
-        quote!(Some(#crate_module.to_owned()))
-    } else if default_to_none {
-        module_path_import = quote! {};
-        quote!(None)
-    } else {
-        module_path_import = quote! {use ::std::module_path as std_module_path_module_path;};
-        quote!(Some(std_module_path_module_path!().to_owned()))
-    };
-    let crate_version = if let Some(crate_version) = crate_version {
-        quote!(Some(
-                <::typenaming::Version as ::std::str::FromStr>::from_str(
-                #crate_version
-            )
-            .expect(&format!("Failed to parse crate version argument '{}'", #crate_version)))
-        )
-    } else if default_to_none {
-        quote!(None)
-    } else {
-        quote!(Some(::typenaming::new_semver_version(
-            env!("CARGO_PKG_VERSION_MAJOR"),
-            env!("CARGO_PKG_VERSION_MINOR"),
-            env!("CARGO_PKG_VERSION_PATCH"),
-            env!("CARGO_PKG_VERSION_PRE")
-        )))
-    };
-    let rustc_version = if let Some(rustc_version) = rustc_version {
-        quote!(Some(
-            <::typenaming::Version as ::std::str::FromStr>::from_str(
-                #rustc_version
-            )
-            .expect(&format!("Failed to parse rustc version argument '{}'", #rustc_version))
-        ))
-    } else if default_to_none {
-        quote!(None)
-    } else {
-        quote!(Some(
-            ::typenaming::rustc_version().expect("Failed to fetch rustc version")
-        ))
-    };
     let generics = generics
         .type_params()
         .map(|x| x.ident.clone())
         .collect::<Vec<_>>();
     let generics = quote!(#(<#generics as ::typenaming::TypeNameable>::type_info()),*);
-    let body = quote! {
-        #module_path_import
-        ::typenaming::TypeInfo::new(
-            #type_name.to_owned(),
-            #crate_name,
-            #crate_module,
-            #crate_version,
-            #rustc_version,
-            vec![
-              #generics
-            ]
-        )
-    };
+    let body = type_info_body(
+        type_name,
+        crate_name,
+        crate_module,
+        crate_version,
+        rustc_version,
+        default_to_none,
+        generics,
+    );
     quote! {
         #[automatically_derived]
         impl #impl_generics TypeQueryable for #ident #ty_generics #where_clause {
@@ -252,3 +339,164 @@ pub fn typequeryable(tokens: TokenStream) -> TokenStream {
     }
     .into()
 }
+
+#[derive(FromMeta, Debug)]
+struct NameableTraitArguments {
+    #[darling(default)]
+    type_name: Option<syn::Ident>,
+    #[darling(default)]
+    crate_name: Option<syn::Ident>,
+    #[darling(default)]
+    crate_module: Option<String>,
+    #[darling(default)]
+    crate_version: Option<String>,
+    #[darling(default)]
+    rustc_version: Option<String>,
+    #[darling(default)]
+    default_to_none: bool,
+}
+
+/// Implements TypeNameable for the trait objects 'dyn Trait', 'dyn Trait + Send', 'dyn Trait + Sync' and 'dyn Trait + Send + Sync'
+/// Generic parameters and associated types of the trait are recorded as generics
+/// This accepts the same arguments as '#[typenameable(...)]'
+#[proc_macro_attribute]
+pub fn nameable_trait(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(args as syn::AttributeArgs);
+    let item = parse_macro_input!(tokens as syn::ItemTrait);
+    let NameableTraitArguments {
+        type_name,
+        crate_name,
+        crate_module,
+        crate_version,
+        rustc_version,
+        default_to_none,
+    } = match NameableTraitArguments::from_list(&args) {
+        Ok(arguments) => arguments,
+        Err(e) => return e.write_errors().into(),
+    };
+
+    let ident = &item.ident;
+    let type_name = format!(
+        "dyn {}",
+        type_name
+            .map(|x| x.to_string())
+            .unwrap_or_else(|| ident.to_string())
+    );
+
+    let trait_arguments = item
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Type(x) => {
+                let ident = &x.ident;
+                quote!(#ident)
+            }
+            syn::GenericParam::Lifetime(x) => {
+                let lifetime = &x.lifetime;
+                quote!(#lifetime)
+            }
+            syn::GenericParam::Const(x) => {
+                let ident = &x.ident;
+                quote!(#ident)
+            }
+        })
+        .collect::<Vec<_>>();
+    let associated_types = item
+        .items
+        .iter()
+        .filter_map(|x| match x {
+            syn::TraitItem::Type(x) if x.generics.params.is_empty() => Some(x.ident.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let associated_parameters = associated_types
+        .iter()
+        .map(|x| format_ident!("__{}", x))
+        .collect::<Vec<_>>();
+
+    let mut generics = item.generics.clone();
+    for param in &mut generics.params {
+        if let syn::GenericParam::Type(ref mut type_param) = *param {
+            type_param
+                .bounds
+                .push(syn::parse_quote!(::typenaming::TypeNameable));
+        }
+    }
+    let first_const = generics
+        .params
+        .iter()
+        .position(|x| matches!(x, syn::GenericParam::Const(_)))
+        .unwrap_or(generics.params.len());
+    for (index, param) in associated_parameters.iter().enumerate() {
+        generics.params.insert(
+            first_const + index,
+            syn::parse_quote!(#param: ::typenaming::TypeNameable),
+        );
+    }
+    generics
+        .params
+        .insert(0, syn::parse_quote!('__typenaming_dyn));
+    if let Some(where_clause) = &mut generics.where_clause {
+        // predicates on 'Self' refer to the implementing type, not to the trait object
+        let self_type: syn::Type = syn::parse_quote!(Self);
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|x| !matches!(x, syn::WherePredicate::Type(x) if x.bounded_ty == self_type))
+            .cloned()
+            .collect();
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let recorded = item
+        .generics
+        .type_params()
+        .map(|x| x.ident.clone())
+        .chain(associated_parameters.iter().cloned())
+        .collect::<Vec<_>>();
+    let recorded = quote!(#(<#recorded as ::typenaming::TypeNameable>::type_info()),*);
+    let trait_path = if trait_arguments.is_empty() && associated_types.is_empty() {
+        quote!(#ident)
+    } else {
+        quote!(#ident<#(#trait_arguments,)* #(#associated_types = #associated_parameters),*>)
+    };
+
+    let impls = [
+        (quote!(), ""),
+        (quote!(+ ::core::marker::Send), " + Send"),
+        (quote!(+ ::core::marker::Sync), " + Sync"),
+        (
+            quote!(+ ::core::marker::Send + ::core::marker::Sync),
+            " + Send + Sync",
+        ),
+    ]
+    .into_iter()
+    .map(|(auto_traits, suffix)| {
+        let body = type_info_body(
+            format!("{type_name}{suffix}"),
+            crate_name.clone(),
+            crate_module.clone(),
+            crate_version.clone(),
+            rustc_version.clone(),
+            default_to_none,
+            recorded.clone(),
+        );
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::typenaming::TypeNameable
+                for dyn #trait_path #auto_traits + '__typenaming_dyn
+            #where_clause
+            {
+                fn type_info() -> ::typenaming::TypeInfo {
+                    #body
+                }
+            }
+        }
+    });
+    quote! {
+        #item
+        #(#impls)*
+    }
+    .into()
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use typenaming::{ParseErrorKind, ParseOptions, TypeInfo, TypeInfoRef};
+
+fn nested(depth: usize) -> String {
+    let inner = "A, Crate=bench, Module=bench::module, Version=1.2.3, Rustc=1.70.0";
+    "A<".repeat(depth) + inner + &">, Crate=bench, Version=1.2.3".repeat(depth)
+}
+
+fn wide(width: usize) -> String {
+    let generics = vec!["u8, Crate=core, Module=core::primitive, Version=1.70.0"; width];
+    format!("Tuple<{}>, Crate=core", generics.join("; "))
+}
+
+fn parse_nested(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_nested");
+    let options = ParseOptions {
+        max_depth: 10_000,
+        ..Default::default()
+    };
+    for depth in [1, 16, 128, 1024] {
+        let input = nested(depth);
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &input, |b, input| {
+            b.iter(|| TypeInfo::from_str_with_options(input, &options).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn parse_wide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_wide");
+    for width in [1, 16, 128, 1024] {
+        let input = wide(width);
+        group.bench_with_input(BenchmarkId::from_parameter(width), &input, |b, input| {
+            b.iter(|| TypeInfo::from_str(input).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn parse_borrowed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_borrowed");
+    let input = wide(16);
+    group.bench_function("owned", |b| b.iter(|| TypeInfo::from_str(&input).unwrap()));
+    group.bench_function("borrowed", |b| {
+        b.iter(|| TypeInfoRef::parse(&input).unwrap())
+    });
+    group.finish();
+}
+
+fn reject_nested(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reject_nested");
+    // The length limit is raised, so that all inputs are rejected by the depth limit
+    let options = ParseOptions {
+        max_length: usize::MAX,
+        ..Default::default()
+    };
+    for depth in [options.max_depth + 1, 100_000] {
+        let input = nested(depth);
+        let error = TypeInfo::from_str_with_options(&input, &options).unwrap_err();
+        assert!(matches!(error.kind(), ParseErrorKind::NestingTooDeep));
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &input, |b, input| {
+            b.iter(|| TypeInfo::from_str_with_options(input, &options).unwrap_err())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    parse_nested,
+    parse_wide,
+    parse_borrowed,
+    reject_nested
+);
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+//! Records the version of the compiler, which is used to build this crate.
+//! The standard library is shipped together with the compiler, so this is also the version of 'core', 'alloc' and 'std'.
+//! Additionally, the resolved versions of the dependencies, for which TypeNameable is implemented, are read from the lockfile of the final build.
+use std::path::{Path, PathBuf};
+
+/// Dependencies, for which TypeNameable is implemented, together with the feature, which enables the implementation - if any
+const DEPENDENCIES: &[(&str, Option<&str>)] = &[
+    ("bytes", Some("bytes")),
+    ("chrono", Some("chrono")),
+    ("half", Some("half")),
+    ("indexmap", Some("indexmap")),
+    ("nalgebra", Some("nalgebra")),
+    ("ndarray", Some("ndarray")),
+    ("num-complex", Some("num-complex")),
+    ("rust_decimal", Some("rust_decimal")),
+    ("semver", None),
+    ("serde_json", Some("serde_json")),
+    ("smallvec", Some("smallvec")),
+    ("time", Some("time")),
+    ("url", Some("url")),
+    ("uuid", Some("uuid")),
+];
+
+fn main() {
+    let version = rustc_version::version().expect("Failed to fetch rustc version");
+    println!("cargo:rustc-env=TYPENAMING_RUSTC_VERSION={version}");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=TYPENAMING_LOCKFILE");
+
+    let enabled: Vec<&str> = DEPENDENCIES
+        .iter()
+        .filter(|(_, feature)| {
+            feature.is_none_or(|feature| {
+                let feature = format!("CARGO_FEATURE_{}", feature.to_uppercase().replace('-', "_"));
+                std::env::var_os(feature).is_some()
+            })
+        })
+        .map(|(name, _)| *name)
+        .collect();
+    let Some((lockfile, dependencies)) = find_dependencies() else {
+        panic!(
+            "Failed to find the Cargo.lock of this build, which lists typenaming {}. Set TYPENAMING_LOCKFILE to its path",
+            env("CARGO_PKG_VERSION")
+        );
+    };
+    println!("cargo:rerun-if-changed={}", lockfile.display());
+    for name in enabled {
+        let Some((_, version)) = dependencies
+            .iter()
+            .find(|(dependency, _)| dependency == name)
+        else {
+            panic!(
+                "'{name}' is not listed as dependency of typenaming in {}",
+                lockfile.display()
+            );
+        };
+        let key = name.to_uppercase().replace('-', "_");
+        println!("cargo:rustc-env=TYPENAMING_VERSION_{key}={version}");
+    }
+}
+
+fn env(key: &str) -> String {
+    std::env::var(key).unwrap_or_else(|_| panic!("{key} not set"))
+}
+
+/// The lockfile of the final build is located in one of the parents of the target directory, of this crate or of the working directory of cargo
+/// The first lockfile, which lists this crate, is used, together with the resolved versions of the dependencies of this crate
+fn find_dependencies() -> Option<(PathBuf, Vec<(String, String)>)> {
+    let explicit = std::env::var_os("TYPENAMING_LOCKFILE").map(PathBuf::from);
+    let ancestors = ["OUT_DIR", "CARGO_MANIFEST_DIR", "PWD"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .flat_map(|dir| {
+            Path::new(&dir)
+                .ancestors()
+                .map(|x| x.join("Cargo.lock"))
+                .collect::<Vec<_>>()
+        });
+    explicit
+        .into_iter()
+        .chain(ancestors)
+        .filter(|x| x.is_file())
+        .find_map(|lockfile| {
+            let content = std::fs::read_to_string(&lockfile).ok()?;
+            let dependencies = resolve(&parse_lockfile(&content))?;
+            Some((lockfile, dependencies))
+        })
+}
+
+struct Package {
+    name: String,
+    version: String,
+    dependencies: Vec<String>,
+}
+
+/// Extracts name, version and dependencies of all packages
+fn parse_lockfile(content: &str) -> Vec<Package> {
+    let mut packages: Vec<Package> = Vec::new();
+    let mut in_dependencies = false;
+    for line in content.lines().map(str::trim) {
+        let value = |prefix: &str| {
+            line.strip_prefix(prefix)
+                .map(|x| x.trim_matches('"').to_owned())
+        };
+        if line == "[[package]]" {
+            packages.push(Package {
+                name: String::new(),
+                version: String::new(),
+                dependencies: Vec::new(),
+            });
+        } else if let Some(package) = packages.last_mut() {
+            if in_dependencies {
+                match line {
+                    "]" => in_dependencies = false,
+                    line => package
+                        .dependencies
+                        .push(line.trim_end_matches(',').trim_matches('"').to_owned()),
+                }
+            } else if let Some(name) = value("name = ") {
+                package.name = name;
+            } else if let Some(version) = value("version = ") {
+                package.version = version;
+            } else if line == "dependencies = [" {
+                in_dependencies = true;
+            }
+        }
+    }
+    packages
+}
+
+/// Resolves the dependencies of this crate to their versions
+/// Entries of the lockfile consist of the name, followed by version and source, if the name is ambiguous
+fn resolve(packages: &[Package]) -> Option<Vec<(String, String)>> {
+    let name = env("CARGO_PKG_NAME");
+    let version = env("CARGO_PKG_VERSION");
+    let package = packages
+        .iter()
+        .find(|x| x.name == name && x.version == version)?;
+    let dependencies = package
+        .dependencies
+        .iter()
+        .filter_map(|entry| {
+            let mut parts = entry.split_whitespace();
+            let name = parts.next()?;
+            let version = match parts.next() {
+                Some(version) => version.to_owned(),
+                None => packages.iter().find(|x| x.name == name)?.version.clone(),
+            };
+            Some((name.to_owned(), version))
+        })
+        .collect();
+    Some(dependencies)
+}
@@ -0,0 +1,593 @@
+//! Writer and reader of the binary format, as generated by 'TypeInfo::to_bytes'
+//!
+//! The layout of the format is
+//!     binary   := format-version strings versions type
+//!     strings  := count { length utf8-bytes }
+//!     versions := count { major minor patch pre build }
+//!     type     := name flags [ crate ] [ module ] [ version ] [ rustc ] [ extra ] count { type }
+//!     extra    := count { key value }
+//! All numbers, including counts and lengths, are unsigned LEB128 varints, the format version is a single byte
+//! Texts are indices into the string table, versions are indices into the version table
+//! Pre-release and build metadata of a version are string indices, shifted by one, where zero denotes an empty text
+//! Types are stored in pre-order, the flags select, which optional fields follow
+//! Each distinct text and version is stored only once, in the order of its first use
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{ParseOptions, TypeInfo, TypeNameable};
+
+const HAS_CRATE: u8 = 1;
+const HAS_MODULE: u8 = 2;
+const HAS_VERSION: u8 = 4;
+const HAS_RUSTC: u8 = 8;
+const HAS_EXTRA: u8 = 16;
+
+/// Collects the distinct texts and versions of a TypeInfo
+#[derive(Default)]
+struct Interner<'a> {
+    strings: Vec<&'a str>,
+    string_indices: HashMap<&'a str, usize>,
+    versions: Vec<&'a semver::Version>,
+    version_indices: HashMap<&'a semver::Version, usize>,
+}
+
+impl<'a> Interner<'a> {
+    fn string(&mut self, s: &'a str) -> usize {
+        *self.string_indices.entry(s).or_insert_with(|| {
+            self.strings.push(s);
+            self.strings.len() - 1
+        })
+    }
+    fn version(&mut self, version: &'a semver::Version) -> usize {
+        if !self.version_indices.contains_key(version) {
+            self.version_indices.insert(version, self.versions.len());
+            self.versions.push(version);
+            self.optional_string(version.pre.as_str());
+            self.optional_string(version.build.as_str());
+        }
+        self.version_indices[version]
+    }
+    /// Empty texts are not interned, as they are encoded as zero
+    fn optional_string(&mut self, s: &'a str) -> usize {
+        if s.is_empty() {
+            0
+        } else {
+            self.string(s) + 1
+        }
+    }
+    fn collect(&mut self, info: &'a TypeInfo) {
+        self.string(info.type_name());
+        if let Some(krate) = info.crate_name() {
+            self.string(krate);
+        }
+        if let Some(module) = info.crate_module() {
+            self.string(module);
+        }
+        if let Some(version) = info.crate_version() {
+            self.version(version);
+        }
+        if let Some(version) = info.rustc_version() {
+            self.version(version);
+        }
+        for (key, value) in info.extra() {
+            self.string(key);
+            self.string(value);
+        }
+        for generic in info.generics() {
+            self.collect(generic);
+        }
+    }
+}
+
+fn write_varint(mut value: u64, out: &mut Vec<u8>) {
+    while value >= 0x80 {
+        out.push((value & 0x7f) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_usize(value: usize, out: &mut Vec<u8>) {
+    write_varint(value as u64, out)
+}
+
+fn write_type(info: &TypeInfo, interner: &Interner, out: &mut Vec<u8>) {
+    let string = |s: &str| interner.string_indices[s];
+    let version = |v: &semver::Version| interner.version_indices[v];
+    write_usize(string(info.type_name()), out);
+    let flags = [
+        (HAS_CRATE, info.crate_name().is_some()),
+        (HAS_MODULE, info.crate_module().is_some()),
+        (HAS_VERSION, info.crate_version().is_some()),
+        (HAS_RUSTC, info.rustc_version().is_some()),
+        (HAS_EXTRA, !info.extra().is_empty()),
+    ];
+    out.push(
+        flags
+            .iter()
+            .filter(|(_, set)| *set)
+            .fold(0, |flags, (flag, _)| flags | flag),
+    );
+    if let Some(krate) = info.crate_name() {
+        write_usize(string(krate), out);
+    }
+    if let Some(module) = info.crate_module() {
+        write_usize(string(module), out);
+    }
+    if let Some(v) = info.crate_version() {
+        write_usize(version(v), out);
+    }
+    if let Some(v) = info.rustc_version() {
+        write_usize(version(v), out);
+    }
+    if !info.extra().is_empty() {
+        write_usize(info.extra().len(), out);
+        for (key, value) in info.extra() {
+            write_usize(string(key), out);
+            write_usize(string(value), out);
+        }
+    }
+    write_usize(info.generics().len(), out);
+    for generic in info.generics() {
+        write_type(generic, interner, out);
+    }
+}
+
+/// Writes a TypeInfo in the binary format, including the format version
+pub(crate) fn write(info: &TypeInfo) -> Vec<u8> {
+    let mut interner = Interner::default();
+    interner.collect(info);
+    let mut out = vec![TypeInfo::BINARY_FORMAT_VERSION];
+    write_usize(interner.strings.len(), &mut out);
+    for s in &interner.strings {
+        write_usize(s.len(), &mut out);
+        out.extend_from_slice(s.as_bytes());
+    }
+    write_usize(interner.versions.len(), &mut out);
+    for version in &interner.versions {
+        write_varint(version.major, &mut out);
+        write_varint(version.minor, &mut out);
+        write_varint(version.patch, &mut out);
+        let optional = |s: &str| {
+            if s.is_empty() {
+                0
+            } else {
+                interner.string_indices[s] + 1
+            }
+        };
+        write_usize(optional(version.pre.as_str()), &mut out);
+        write_usize(optional(version.build.as_str()), &mut out);
+    }
+    write_type(info, &interner, &mut out);
+    out
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn error(&self, kind: DecodeErrorKind) -> DecodeError {
+        DecodeError {
+            kind,
+            offset: self.offset,
+        }
+    }
+    fn byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self
+            .bytes
+            .get(self.offset)
+            .ok_or_else(|| self.error(DecodeErrorKind::UnexpectedEnd))?;
+        self.offset += 1;
+        Ok(byte)
+    }
+    fn varint(&mut self) -> Result<u64, DecodeError> {
+        let start = self.offset;
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            let bits = u64::from(byte & 0x7f);
+            if shift == 63 && bits > 1 {
+                break;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError {
+            kind: DecodeErrorKind::VarintOverflow,
+            offset: start,
+        })
+    }
+    fn usize(&mut self) -> Result<usize, DecodeError> {
+        let start = self.offset;
+        let value = self.varint()?;
+        usize::try_from(value).map_err(|_| DecodeError {
+            kind: DecodeErrorKind::VarintOverflow,
+            offset: start,
+        })
+    }
+    /// Reads a count of items, each of which takes at least one byte, hence the count is bounded by the remaining input
+    fn count(&mut self) -> Result<usize, DecodeError> {
+        let start = self.offset;
+        let count = self.usize()?;
+        if count > self.bytes.len() - self.offset {
+            Err(DecodeError {
+                kind: DecodeErrorKind::UnexpectedEnd,
+                offset: start,
+            })
+        } else {
+            Ok(count)
+        }
+    }
+    fn index<'t, T>(&mut self, table: &'t [T]) -> Result<&'t T, DecodeError> {
+        let start = self.offset;
+        let index = self.usize()?;
+        table.get(index).ok_or(DecodeError {
+            kind: DecodeErrorKind::InvalidIndex,
+            offset: start,
+        })
+    }
+    fn string(&mut self) -> Result<&'a str, DecodeError> {
+        let length = self.count()?;
+        let bytes = &self.bytes[self.offset..self.offset + length];
+        let s = std::str::from_utf8(bytes).map_err(|_| self.error(DecodeErrorKind::InvalidUtf8))?;
+        self.offset += length;
+        Ok(s)
+    }
+    fn optional_string(&mut self, strings: &[&'a str]) -> Result<&'a str, DecodeError> {
+        let start = self.offset;
+        match self.usize()? {
+            0 => Ok(""),
+            index => strings.get(index - 1).copied().ok_or(DecodeError {
+                kind: DecodeErrorKind::InvalidIndex,
+                offset: start,
+            }),
+        }
+    }
+    fn version(&mut self, strings: &[&'a str]) -> Result<semver::Version, DecodeError> {
+        let start = self.offset;
+        let major = self.varint()?;
+        let minor = self.varint()?;
+        let patch = self.varint()?;
+        let invalid = |e| DecodeError {
+            kind: DecodeErrorKind::InvalidVersion(e),
+            offset: start,
+        };
+        let pre = semver::Prerelease::new(self.optional_string(strings)?).map_err(invalid)?;
+        let build = semver::BuildMetadata::new(self.optional_string(strings)?).map_err(invalid)?;
+        Ok(semver::Version {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+}
+
+/// Reads the fields of a type, up to and including the number of its generics
+fn read_node(
+    reader: &mut Reader,
+    strings: &[&str],
+    versions: &[semver::Version],
+) -> Result<(TypeInfo, usize), DecodeError> {
+    let name = reader.index(strings)?;
+    let flags_offset = reader.offset;
+    let flags = reader.byte()?;
+    if flags & !(HAS_CRATE | HAS_MODULE | HAS_VERSION | HAS_RUSTC | HAS_EXTRA) != 0 {
+        return Err(DecodeError {
+            kind: DecodeErrorKind::InvalidFlags,
+            offset: flags_offset,
+        });
+    }
+    let mut string = |flag| -> Result<_, DecodeError> {
+        Ok(if flags & flag != 0 {
+            Some(reader.index(strings)?.to_string())
+        } else {
+            None
+        })
+    };
+    let krate = string(HAS_CRATE)?;
+    let module = string(HAS_MODULE)?;
+    let mut version = |flag| -> Result<_, DecodeError> {
+        Ok(if flags & flag != 0 {
+            Some(reader.index(versions)?.clone())
+        } else {
+            None
+        })
+    };
+    let crate_version = version(HAS_VERSION)?;
+    let rustc_version = version(HAS_RUSTC)?;
+    let mut extra = BTreeMap::new();
+    if flags & HAS_EXTRA != 0 {
+        for _ in 0..reader.count()? {
+            let key_offset = reader.offset;
+            let key = reader.index(strings)?;
+            let value = reader.index(strings)?;
+            if extra.insert(key.to_string(), value.to_string()).is_some() {
+                return Err(DecodeError {
+                    kind: DecodeErrorKind::ExtraSetTwice,
+                    offset: key_offset,
+                });
+            }
+        }
+    }
+    let generics = reader.count()?;
+    let info = TypeInfo::new(
+        name.to_string(),
+        krate,
+        module,
+        crate_version,
+        rustc_version,
+        Vec::with_capacity(generics),
+    )
+    .with_extra(extra);
+    Ok((info, generics))
+}
+
+/// Reads a TypeInfo in the binary format, including the format version
+/// Types are read iteratively, hence deeply nested input does not overflow the stack
+pub(crate) fn read(bytes: &[u8], options: &ParseOptions) -> Result<TypeInfo, DecodeError> {
+    let mut reader = Reader { bytes, offset: 0 };
+    if bytes.len() > options.max_length {
+        return Err(reader.error(DecodeErrorKind::InputTooLong));
+    }
+    if reader.byte()? != TypeInfo::BINARY_FORMAT_VERSION {
+        return Err(DecodeError {
+            kind: DecodeErrorKind::UnsupportedFormatVersion,
+            offset: 0,
+        });
+    }
+    let strings = (0..reader.count()?)
+        .map(|_| reader.string())
+        .collect::<Result<Vec<_>, _>>()?;
+    let versions = (0..reader.count()?)
+        .map(|_| reader.version(&strings))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Each entry is a type, whose generics are not yet complete, together with the number of missing generics
+    let mut stack: Vec<(TypeInfo, usize)> = Vec::new();
+    let mut node = read_node(&mut reader, &strings, &versions)?;
+    let info = loop {
+        if node.1 > 0 {
+            if stack.len() >= options.max_depth {
+                return Err(reader.error(DecodeErrorKind::NestingTooDeep));
+            }
+            stack.push(node);
+            node = read_node(&mut reader, &strings, &versions)?;
+            continue;
+        }
+        let complete = node.0;
+        match stack.pop() {
+            Some((mut parent, missing)) => {
+                parent.push_generic(complete);
+                node = (parent, missing - 1);
+            }
+            None => break complete,
+        }
+    };
+    if reader.offset != bytes.len() {
+        return Err(reader.error(DecodeErrorKind::TrailingData));
+    }
+    Ok(info)
+}
+
+/// This error is returned, if bytes cannot be decoded into a TypeInfo
+/// Besides the kind of error, it contains the byte offset of the error
+#[derive(Debug, TypeNameable)]
+pub struct DecodeError {
+    kind: DecodeErrorKind,
+    offset: usize,
+}
+
+impl DecodeError {
+    /// Get kind of error
+    pub fn kind(&self) -> &DecodeErrorKind {
+        &self.kind
+    }
+    /// Get byte offset into the input, at which the error was detected
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            DecodeErrorKind::InvalidVersion(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// This describes, why bytes cannot be decoded into a TypeInfo
+#[derive(Debug, TypeNameable)]
+pub enum DecodeErrorKind {
+    /// The input ends, before the TypeInfo is complete
+    UnexpectedEnd,
+    /// The first byte denotes an unknown format version
+    UnsupportedFormatVersion,
+    /// A varint does not fit into 64 bits, or a count does not fit into 'usize'
+    VarintOverflow,
+    /// A text is not valid UTF-8
+    InvalidUtf8,
+    /// An index into the string table or the version table is out of range
+    InvalidIndex,
+    /// The flags of a type contain unknown bits
+    InvalidFlags,
+    /// The pre-release or build metadata of a version is invalid
+    InvalidVersion(semver::Error),
+    /// An extra entry is given twice
+    ExtraSetTwice,
+    /// The generics are nested deeper than allowed by 'ParseOptions::max_depth'
+    NestingTooDeep,
+    /// The input is longer than allowed by 'ParseOptions::max_length'
+    InputTooLong,
+    /// A complete TypeInfo was decoded, but some bytes are left
+    TrailingData,
+}
+
+impl std::fmt::Display for DecodeErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of input"),
+            Self::UnsupportedFormatVersion => f.write_str("unsupported format version"),
+            Self::VarintOverflow => f.write_str("varint too large"),
+            Self::InvalidUtf8 => f.write_str("invalid UTF-8"),
+            Self::InvalidIndex => f.write_str("index out of range"),
+            Self::InvalidFlags => f.write_str("unknown flags"),
+            Self::InvalidVersion(e) => write!(f, "invalid version: {e}"),
+            Self::ExtraSetTwice => f.write_str("duplicate entry"),
+            Self::NestingTooDeep => f.write_str("generics nested too deeply"),
+            Self::InputTooLong => f.write_str("input too long"),
+            Self::TrailingData => f.write_str("unexpected data after complete type"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecodeErrorKind, ParseOptions, TypeInfo, TypeNameable};
+
+    fn decode_error(bytes: &[u8]) -> DecodeErrorKind {
+        TypeInfo::from_bytes(bytes).unwrap_err().kind
+    }
+
+    #[test]
+    fn roundtrip() {
+        let infos = [
+            Vec::<Result<u8, String>>::type_info(),
+            <[Vec<u8>; 3]>::type_info(),
+            <fn(u8) -> bool>::type_info(),
+            TypeInfo::new_const_argument("-3".to_owned()),
+            TypeInfo::new(
+                String::new(),
+                Some("krate".to_owned()),
+                None,
+                Some("1.2.3-alpha.1+build.5".parse().unwrap()),
+                Some("1.2.3".parse().unwrap()),
+                vec![],
+            )
+            .with_extra([("Size".to_owned(), "24".to_owned())].into()),
+        ];
+        for info in infos {
+            assert_eq!(info, TypeInfo::from_bytes(&info.to_bytes()).unwrap());
+        }
+    }
+
+    #[test]
+    fn interned() {
+        // The versions are given explicitly, since they are only known with the feature 'std-versions'
+        let version: Option<crate::Version> = Some("1.70.0".parse().unwrap());
+        let std = |name: &str, module: &str, generics| {
+            TypeInfo::new(
+                name.to_owned(),
+                module.split("::").next().map(str::to_owned),
+                Some(module.to_owned()),
+                version.clone(),
+                version.clone(),
+                generics,
+            )
+        };
+        let vec = |generic| std("Vec", "alloc::vec", vec![generic]);
+        // Vec<Result<Vec<u8>, Vec<u16>>>
+        let info = vec(std(
+            "Result",
+            "core::result",
+            vec![
+                vec(std("u8", "core::primitive", vec![])),
+                vec(std("u16", "core::primitive", vec![])),
+            ],
+        ));
+        let bytes = info.to_bytes();
+        let needle = b"alloc::vec";
+        let count = bytes.windows(needle.len()).filter(|w| w == needle).count();
+        assert_eq!(1, count);
+        assert!(bytes.len() * 3 < info.to_one_line_string().len());
+    }
+
+    #[test]
+    fn malformed() {
+        let bytes = u8::type_info().to_bytes();
+        assert!(matches!(decode_error(&[]), DecodeErrorKind::UnexpectedEnd));
+        assert!(matches!(
+            decode_error(&[2]),
+            DecodeErrorKind::UnsupportedFormatVersion
+        ));
+        for end in 1..bytes.len() {
+            assert!(matches!(
+                decode_error(&bytes[..end]),
+                DecodeErrorKind::UnexpectedEnd
+            ));
+        }
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        let error = TypeInfo::from_bytes(&trailing).unwrap_err();
+        assert!(matches!(error.kind(), DecodeErrorKind::TrailingData));
+        assert_eq!(bytes.len(), error.offset());
+        // One string "\xff", no versions, a type named by string 0 without flags and generics
+        assert!(matches!(
+            decode_error(&[1, 1, 1, 0xff, 0, 0, 0, 0]),
+            DecodeErrorKind::InvalidUtf8
+        ));
+        assert!(matches!(
+            decode_error(&[1, 1, 1, b'a', 0, 1, 0, 0]),
+            DecodeErrorKind::InvalidIndex
+        ));
+        assert!(matches!(
+            decode_error(&[1, 1, 1, b'a', 0, 0, 0x80, 0]),
+            DecodeErrorKind::InvalidFlags
+        ));
+        assert!(matches!(
+            decode_error(&[1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f]),
+            DecodeErrorKind::VarintOverflow
+        ));
+    }
+
+    #[test]
+    fn limits() {
+        let mut info = u8::type_info();
+        for _ in 0..10 {
+            info = TypeInfo::new("Box".to_owned(), None, None, None, None, vec![info]);
+        }
+        let bytes = info.to_bytes();
+        let options = ParseOptions {
+            max_depth: 9,
+            ..Default::default()
+        };
+        assert!(matches!(
+            TypeInfo::from_bytes_with_options(&bytes, &options)
+                .unwrap_err()
+                .kind(),
+            DecodeErrorKind::NestingTooDeep
+        ));
+        let options = ParseOptions {
+            max_depth: 10,
+            max_length: bytes.len() - 1,
+            ..Default::default()
+        };
+        assert!(matches!(
+            TypeInfo::from_bytes_with_options(&bytes, &options)
+                .unwrap_err()
+                .kind(),
+            DecodeErrorKind::InputTooLong
+        ));
+        let options = ParseOptions {
+            max_depth: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            info,
+            TypeInfo::from_bytes_with_options(&bytes, &options).unwrap()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use proc_macro2::{Ident, TokenStream, TokenTree};
+use quote::quote;
+
+use crate::TypeInfo;
+
+impl TypeInfo {
+    /// Generates the Rust type, which is described by this TypeInfo, e.g. `::std::vec::Vec<::core::primitive::u32>`
+    /// Types are qualified by their module, standard library types by their public module in 'core' or 'std', since 'alloc' is not available without 'extern crate alloc' primitives, arrays, slices, pointers, tuples, function pointers and trait objects use Rust syntax
+    /// Note that associated types, e.g. the 'Item' of `dyn Iterator<Item = u8>`, are generated as positional generics
+    /// This fails, if a type name or a module segment is not a Rust identifier
+    pub fn to_type_tokens(&self) -> Result<TokenStream, CodegenError> {
+        type_tokens(self)
+    }
+}
+
+/// This error is returned by 'TypeInfo::to_type_tokens', if a text is not a Rust identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodegenError {
+    text: String,
+}
+
+impl CodegenError {
+    /// Get text, which is not a Rust identifier
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl std::fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a Rust identifier", self.text)
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+fn ident(text: &str) -> Result<Ident, CodegenError> {
+    let error = || CodegenError {
+        text: text.to_owned(),
+    };
+    let tokens = text.parse::<TokenStream>().map_err(|_| error())?;
+    let mut tokens = tokens.into_iter();
+    match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Ident(ident)), None) => Ok(ident),
+        _ => Err(error()),
+    }
+}
+
+fn list(generics: &[TypeInfo]) -> Result<Vec<TokenStream>, CodegenError> {
+    generics.iter().map(type_tokens).collect()
+}
+
+/// Maps a module of the standard library to the public module, which is reachable from every crate, e.g. 'alloc::vec' to 'std::vec'
+fn public_module(info: &TypeInfo, module: &str) -> String {
+    if !matches!(info.crate_name().as_deref(), Some("core" | "alloc" | "std")) {
+        return module.to_owned();
+    }
+    let module = crate::std_impl::public_module(module);
+    match module.strip_prefix("alloc") {
+        Some(rest) if rest.is_empty() || rest.starts_with("::") => format!("std{rest}"),
+        _ => module.to_owned(),
+    }
+}
+
+/// Generates a path, which is qualified by the module, e.g. '::std::vec::Vec<…>'
+fn path_tokens(info: &TypeInfo, name: &str) -> Result<TokenStream, CodegenError> {
+    let name = ident(name)?;
+    let segments = match info.crate_module().as_deref() {
+        Some(module) if !module.is_empty() => public_module(info, module)
+            .split("::")
+            .map(ident)
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => Vec::new(),
+    };
+    let generics = list(info.generics())?;
+    let generics = if generics.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#generics),*>)
+    };
+    if segments.is_empty() {
+        Ok(quote!(#name #generics))
+    } else {
+        Ok(quote!(#(:: #segments)* :: #name #generics))
+    }
+}
+
+fn type_tokens(info: &TypeInfo) -> Result<TokenStream, CodegenError> {
+    let name = info.type_name();
+    let generics = info.generics();
+    let is_primitive = info.crate_module().as_deref() == Some("core::primitive");
+    if info.is_const_argument() {
+        let value = name.parse::<TokenStream>().map_err(|_| CodegenError {
+            text: name.to_owned(),
+        })?;
+        // Negative values have to be enclosed by braces
+        return Ok(if name.starts_with('-') {
+            quote!({ #value })
+        } else {
+            value
+        });
+    }
+    Ok(match generics {
+        [element, length] if is_primitive && name == "array" => {
+            let element = type_tokens(element)?;
+            let length = type_tokens(length)?;
+            quote!([#element; #length])
+        }
+        [element] if is_primitive && name == "slice" => {
+            let element = type_tokens(element)?;
+            quote!([#element])
+        }
+        [pointee] if is_primitive && name == "*const" => {
+            let pointee = type_tokens(pointee)?;
+            quote!(*const #pointee)
+        }
+        [pointee] if is_primitive && name == "*mut" => {
+            let pointee = type_tokens(pointee)?;
+            quote!(*mut #pointee)
+        }
+        [] if is_primitive && name == "()" => quote!(()),
+        [] if is_primitive && name == "!" => quote!(!),
+        [element] if is_primitive && name == "tuple" => {
+            let element = type_tokens(element)?;
+            quote!((#element,))
+        }
+        _ if is_primitive && name == "tuple" => {
+            let elements = list(generics)?;
+            quote!((#(#elements),*))
+        }
+        _ if info.is_function_pointer() => {
+            let (output, inputs) = generics
+                .split_last()
+                .expect("Function pointers have an output");
+            let qualifiers = name
+                .strip_suffix("fn")
+                .expect("Function pointers are named like 'fn'")
+                .parse::<TokenStream>()
+                .expect("Function pointer qualifiers are valid tokens");
+            let inputs = list(inputs)?;
+            let output = if output.type_name() == "()" && output.generics().is_empty() {
+                quote!()
+            } else {
+                let output = type_tokens(output)?;
+                quote!(-> #output)
+            };
+            quote!(#qualifiers fn(#(#inputs),*) #output)
+        }
+        _ => match name.strip_prefix("dyn ") {
+            // Trait objects are named like 'dyn Trait + Send', the path and the generics belong to 'Trait'
+            Some(name) => {
+                let mut traits = name.split(" + ");
+                let name = traits.next().expect("Split yields at least one item");
+                let path = path_tokens(info, name)?;
+                let auto_traits = traits.map(ident).collect::<Result<Vec<_>, _>>()?;
+                quote!(dyn #path #(+ ::core::marker::#auto_traits)*)
+            }
+            None if is_primitive => {
+                let name = ident(name)?;
+                quote!(::core::primitive::#name)
+            }
+            None => path_tokens(info, name)?,
+        },
+    })
+}
@@ -0,0 +1,4 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(Bytes = bytes::Bytes);
+implementing_dependency!(BytesMut = bytes::BytesMut);
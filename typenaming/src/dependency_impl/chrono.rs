@@ -0,0 +1,9 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(DateTime = chrono::DateTime, Tz: chrono::TimeZone);
+implementing_dependency!(NaiveDate = chrono::NaiveDate);
+implementing_dependency!(NaiveTime = chrono::NaiveTime);
+implementing_dependency!(NaiveDateTime = chrono::NaiveDateTime);
+implementing_dependency!(Utc = chrono::Utc);
+implementing_dependency!(FixedOffset = chrono::FixedOffset);
+implementing_dependency!(TimeDelta = chrono::TimeDelta);
@@ -0,0 +1,4 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(f16 = half::f16);
+implementing_dependency!(bf16 = half::bf16);
@@ -0,0 +1,32 @@
+/// This is an unstable helper macro, which is used to implement TypeNameable for types from dependencies
+/// The type has to be given by its public path, e.g. 'Utc = chrono::Utc', which is recorded as crate and module of the type
+/// Generic parameters may carry a single trait bound, e.g. 'Tz: chrono::TimeZone'
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implementing_dependency {
+    ( $t:ident = $($p:ident)::+ $(, $g:ident $(: $($b:ident)::+)?)* ) => {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        pub mod $t {
+            use $crate::{TypeNameable, TypeInfo};
+
+            impl<$($g,)*> TypeNameable for $($p)::+<$($g,)*>
+            where
+                $($g: TypeNameable $(+ $($b)::+)?,)*
+            {
+                fn type_info() -> TypeInfo {
+                    $crate::dependency_impl::info(&[$(stringify!($p)),+], vec![$($g::type_info(),)*])
+                }
+            }
+
+            impl<'a, $($g,)*> TypeNameable for &'a $($p)::+<$($g,)*>
+            where
+                $($g: TypeNameable $(+ $($b)::+)?,)*
+            {
+                fn type_info() -> TypeInfo {
+                    $crate::dependency_impl::info(&[$(stringify!($p)),+], vec![$($g::type_info(),)*])
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,4 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(IndexMap = indexmap::IndexMap, K, V, S);
+implementing_dependency!(IndexSet = indexmap::IndexSet, T, S);
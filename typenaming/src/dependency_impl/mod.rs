@@ -0,0 +1,112 @@
+use crate::TypeInfo;
+
+#[cfg(feature = "bytes")]
+/// This module implements TypeName for types from 'bytes'
+pub mod bytes;
+#[cfg(feature = "chrono")]
+/// This module implements TypeName for types from 'chrono'
+pub mod chrono;
+#[cfg(feature = "half")]
+/// This module implements TypeName for types from 'half'
+pub mod half;
+mod impl_macro;
+#[cfg(feature = "indexmap")]
+/// This module implements TypeName for types from 'indexmap'
+pub mod indexmap;
+#[cfg(feature = "nalgebra")]
+/// This module implements TypeName for types from 'nalgebra'
+pub mod nalgebra;
+#[cfg(feature = "ndarray")]
+/// This module implements TypeName for types from 'ndarray'
+pub mod ndarray;
+#[cfg(feature = "num-complex")]
+/// This module implements TypeName for types from 'num-complex'
+pub mod num_complex;
+#[cfg(feature = "rust_decimal")]
+/// This module implements TypeName for types from 'rust_decimal'
+pub mod rust_decimal;
+/// This module implements TypeName for types from 'semver'
+pub mod semver;
+#[cfg(feature = "serde_json")]
+/// This module implements TypeName for types from 'serde_json'
+pub mod serde_json;
+#[cfg(feature = "smallvec")]
+/// This module implements TypeName for types from 'smallvec'
+pub mod smallvec;
+#[cfg(feature = "time")]
+/// This module implements TypeName for types from 'time'
+pub mod time;
+#[cfg(feature = "url")]
+/// This module implements TypeName for types from 'url'
+pub mod url;
+#[cfg(feature = "uuid")]
+/// This module implements TypeName for types from 'uuid'
+pub mod uuid;
+
+/// This is the version of the given dependency, which is recorded in TypeInfo - if TypeNameable is implemented for its types
+/// The build script reads the resolved version from the lockfile of the final build
+#[doc(hidden)]
+pub fn dependency_version(krate: &str) -> Option<crate::Version> {
+    match krate {
+        #[cfg(feature = "bytes")]
+        "bytes" => Some(recorded(env!("TYPENAMING_VERSION_BYTES"))),
+        #[cfg(feature = "chrono")]
+        "chrono" => Some(recorded(env!("TYPENAMING_VERSION_CHRONO"))),
+        #[cfg(feature = "half")]
+        "half" => Some(recorded(env!("TYPENAMING_VERSION_HALF"))),
+        #[cfg(feature = "indexmap")]
+        "indexmap" => Some(recorded(env!("TYPENAMING_VERSION_INDEXMAP"))),
+        #[cfg(feature = "nalgebra")]
+        "nalgebra" => Some(recorded(env!("TYPENAMING_VERSION_NALGEBRA"))),
+        #[cfg(feature = "ndarray")]
+        "ndarray" => Some(recorded(env!("TYPENAMING_VERSION_NDARRAY"))),
+        #[cfg(feature = "num-complex")]
+        "num_complex" => Some(recorded(env!("TYPENAMING_VERSION_NUM_COMPLEX"))),
+        #[cfg(feature = "rust_decimal")]
+        "rust_decimal" => Some(recorded(env!("TYPENAMING_VERSION_RUST_DECIMAL"))),
+        "semver" => Some(recorded(env!("TYPENAMING_VERSION_SEMVER"))),
+        #[cfg(feature = "serde_json")]
+        "serde_json" => Some(recorded(env!("TYPENAMING_VERSION_SERDE_JSON"))),
+        #[cfg(feature = "smallvec")]
+        "smallvec" => Some(recorded(env!("TYPENAMING_VERSION_SMALLVEC"))),
+        #[cfg(feature = "time")]
+        "time" => Some(recorded(env!("TYPENAMING_VERSION_TIME"))),
+        #[cfg(feature = "url")]
+        "url" => Some(recorded(env!("TYPENAMING_VERSION_URL"))),
+        #[cfg(feature = "uuid")]
+        "uuid" => Some(recorded(env!("TYPENAMING_VERSION_UUID"))),
+        _ => None,
+    }
+}
+
+fn recorded(version: &str) -> crate::Version {
+    <crate::Version as std::str::FromStr>::from_str(version)
+        .expect("Failed to parse dependency version recorded by build script")
+}
+
+/// This generates the TypeInfo of a type from a dependency, which is given by its public path, e.g. 'chrono::DateTime'
+/// The crate is the first segment of the path, the module consists of all but the last segment
+#[doc(hidden)]
+pub fn info(path: &[&str], generics: Vec<TypeInfo>) -> TypeInfo {
+    let (name, module) = path
+        .split_last()
+        .expect("The path of a dependency type must not be empty");
+    alias_info(path[0], &module.join("::"), name, generics)
+}
+
+/// This generates the TypeInfo of a type alias from a dependency, e.g. 'SMatrix' from 'nalgebra'
+/// Aliases are used, if their generics are more meaningful than those of the underlying type
+#[doc(hidden)]
+pub fn alias_info(krate: &str, module: &str, name: &str, generics: Vec<TypeInfo>) -> TypeInfo {
+    TypeInfo::new(
+        name.to_owned(),
+        Some(krate.to_owned()),
+        Some(module.to_owned()),
+        Some(
+            dependency_version(krate)
+                .unwrap_or_else(|| panic!("TypeNameable is not implemented for crate '{krate}'")),
+        ),
+        Some(crate::std_impl::compiler_version()),
+        generics,
+    )
+}
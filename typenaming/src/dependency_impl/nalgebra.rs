@@ -0,0 +1,33 @@
+use crate::{TypeInfo, TypeNameable};
+
+/// Statically sized matrices are described by their element type, followed by the number of rows and columns as const arguments
+fn info<T: TypeNameable>(rows: usize, columns: usize) -> TypeInfo {
+    super::alias_info(
+        "nalgebra",
+        "nalgebra",
+        "SMatrix",
+        vec![
+            T::type_info(),
+            TypeInfo::new_const_argument(rows.to_string()),
+            TypeInfo::new_const_argument(columns.to_string()),
+        ],
+    )
+}
+
+impl<T, const R: usize, const C: usize> TypeNameable for nalgebra::SMatrix<T, R, C>
+where
+    T: TypeNameable,
+{
+    fn type_info() -> TypeInfo {
+        info::<T>(R, C)
+    }
+}
+
+impl<T, const R: usize, const C: usize> TypeNameable for &nalgebra::SMatrix<T, R, C>
+where
+    T: TypeNameable,
+{
+    fn type_info() -> TypeInfo {
+        info::<T>(R, C)
+    }
+}
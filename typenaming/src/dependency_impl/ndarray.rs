@@ -0,0 +1,31 @@
+use crate::{TypeInfo, TypeNameable};
+
+/// Owned arrays are described by their element type, followed by the number of dimensions as const argument
+/// If the number of dimensions is dynamic, e.g. for 'IxDyn', the const argument is omitted
+fn info<T: TypeNameable, D: ndarray::Dimension>() -> TypeInfo {
+    let mut generics = vec![T::type_info()];
+    if let Some(dimensions) = D::NDIM {
+        generics.push(TypeInfo::new_const_argument(dimensions.to_string()));
+    }
+    super::alias_info("ndarray", "ndarray", "Array", generics)
+}
+
+impl<T, D> TypeNameable for ndarray::Array<T, D>
+where
+    T: TypeNameable,
+    D: ndarray::Dimension,
+{
+    fn type_info() -> TypeInfo {
+        info::<T, D>()
+    }
+}
+
+impl<T, D> TypeNameable for &ndarray::Array<T, D>
+where
+    T: TypeNameable,
+    D: ndarray::Dimension,
+{
+    fn type_info() -> TypeInfo {
+        info::<T, D>()
+    }
+}
@@ -0,0 +1,3 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(Complex = num_complex::Complex, T);
@@ -0,0 +1,3 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(Decimal = rust_decimal::Decimal);
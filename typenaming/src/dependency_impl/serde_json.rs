@@ -0,0 +1,3 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(Value = serde_json::Value);
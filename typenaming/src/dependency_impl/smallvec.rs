@@ -0,0 +1,22 @@
+use crate::{TypeInfo, TypeNameable};
+
+// The inline capacity is part of the array type, so it is recorded as const argument of the array
+impl<T, const N: usize> TypeNameable for smallvec::SmallVec<[T; N]>
+where
+    T: TypeNameable,
+    [T; N]: smallvec::Array<Item = T>,
+{
+    fn type_info() -> TypeInfo {
+        super::info(&["smallvec", "SmallVec"], vec![<[T; N]>::type_info()])
+    }
+}
+
+impl<T, const N: usize> TypeNameable for &smallvec::SmallVec<[T; N]>
+where
+    T: TypeNameable,
+    [T; N]: smallvec::Array<Item = T>,
+{
+    fn type_info() -> TypeInfo {
+        super::info(&["smallvec", "SmallVec"], vec![<[T; N]>::type_info()])
+    }
+}
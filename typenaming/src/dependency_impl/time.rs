@@ -0,0 +1,7 @@
+use crate::implementing_dependency;
+
+implementing_dependency!(OffsetDateTime = time::OffsetDateTime);
+implementing_dependency!(PrimitiveDateTime = time::PrimitiveDateTime);
+implementing_dependency!(Date = time::Date);
+implementing_dependency!(Time = time::Time);
+implementing_dependency!(UtcOffset = time::UtcOffset);
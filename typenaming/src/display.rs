@@ -0,0 +1,288 @@
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::TypeInfo;
+
+/// These options configure the Rust-like rendering of a TypeInfo, see 'TypeInfo::display_with'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DisplayOptions {
+    /// Prefix types by their module, e.g. `alloc::vec::Vec<u32>` instead of `Vec<u32>` - defaults to false
+    pub qualified: bool,
+    /// Types, whose crate version and rustc version are shown - defaults to none
+    pub versions: VersionDisplay,
+}
+
+/// This selects the types, whose versions are shown by 'TypeInfo::display_with'
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VersionDisplay {
+    /// Versions are not shown
+    #[default]
+    None,
+    /// Versions are only shown for the outermost type, e.g. `Vec<u32> (1.70.0, rustc 1.70.0)`
+    TopLevel,
+    /// Versions are shown for all types, including generics
+    All,
+}
+
+/// This renders a TypeInfo in Rust syntax, it is returned by 'TypeInfo::display_with'
+#[derive(Debug, Clone, Copy)]
+pub struct TypeInfoDisplay<'a> {
+    info: &'a TypeInfo,
+    options: DisplayOptions,
+}
+
+/// This renders a TypeInfo as multi-line tree, it is returned by 'TypeInfo::display_tree'
+/// Each line shows one type, followed by its crate, module, crate version and rustc version in aligned columns
+#[derive(Debug, Clone, Copy)]
+pub struct TypeInfoTree<'a> {
+    info: &'a TypeInfo,
+    reference: Option<&'a TypeInfo>,
+}
+
+impl<'a> TypeInfoTree<'a> {
+    /// Marks all types by '*', which differ from the corresponding type in the reference
+    /// Generics, which are only present in the reference, are shown as '(missing)'
+    pub fn with_reference(mut self, reference: &'a TypeInfo) -> Self {
+        self.reference = Some(reference);
+        self
+    }
+}
+
+impl TypeInfo {
+    /// Renders the type and its generics as tree, using box-drawing characters
+    pub fn display_tree(&self) -> TypeInfoTree<'_> {
+        TypeInfoTree {
+            info: self,
+            reference: None,
+        }
+    }
+    /// Renders the type in Rust syntax with the given options
+    /// Note that 'Display' renders `Vec<u32>`, while the alternate form '{:#}' renders `alloc::vec::Vec<u32>`
+    pub fn display_with(&self, options: DisplayOptions) -> TypeInfoDisplay<'_> {
+        TypeInfoDisplay {
+            info: self,
+            options,
+        }
+    }
+}
+
+impl Display for TypeInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let options = DisplayOptions {
+            qualified: f.alternate(),
+            ..Default::default()
+        };
+        write_type(self, &options, true, f)
+    }
+}
+
+impl Display for TypeInfoDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_type(self.info, &self.options, true, f)
+    }
+}
+
+fn write_list(
+    generics: &[TypeInfo],
+    options: &DisplayOptions,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    for (i, generic) in generics.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write_type(generic, options, false, f)?;
+    }
+    Ok(())
+}
+
+fn write_type(
+    info: &TypeInfo,
+    options: &DisplayOptions,
+    top_level: bool,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    let name = info.type_name();
+    let generics = info.generics();
+    let is_primitive = info.crate_module().as_deref() == Some("core::primitive");
+    match generics {
+        [element, length] if is_primitive && name == "array" => {
+            f.write_char('[')?;
+            write_type(element, options, false, f)?;
+            write!(f, "; {}]", length.type_name())?;
+        }
+        [element] if is_primitive && name == "slice" => {
+            f.write_char('[')?;
+            write_type(element, options, false, f)?;
+            f.write_char(']')?;
+        }
+        [pointee] if is_primitive && (name == "*const" || name == "*mut") => {
+            write!(f, "{name} ")?;
+            write_type(pointee, options, false, f)?;
+        }
+        _ if is_primitive && name == "tuple" => {
+            f.write_char('(')?;
+            write_list(generics, options, f)?;
+            if generics.len() == 1 {
+                f.write_char(',')?;
+            }
+            f.write_char(')')?;
+        }
+        _ if info.is_function_pointer() => {
+            let (output, inputs) = generics
+                .split_last()
+                .expect("Function pointers have an output");
+            write!(f, "{name}(")?;
+            write_list(inputs, options, f)?;
+            f.write_char(')')?;
+            if !(output.type_name() == "()" && output.generics().is_empty()) {
+                f.write_str(" -> ")?;
+                write_type(output, options, false, f)?;
+            }
+        }
+        _ => {
+            // Trait objects are named like 'dyn Trait + Send', the path and the generics belong to 'Trait'
+            let (prefix, name, auto_traits) = match name.strip_prefix("dyn ") {
+                Some(name) => match name.split_once(" + ") {
+                    Some((name, auto_traits)) => ("dyn ", name, Some(auto_traits)),
+                    None => ("dyn ", name, None),
+                },
+                None => ("", name, None),
+            };
+            f.write_str(prefix)?;
+            if let Some(module) = info.crate_module().as_deref().filter(|_| options.qualified) {
+                if !is_primitive {
+                    write!(f, "{module}::")?;
+                }
+            }
+            f.write_str(name)?;
+            if !generics.is_empty() {
+                f.write_char('<')?;
+                write_list(generics, options, f)?;
+                f.write_char('>')?;
+            }
+            if let Some(auto_traits) = auto_traits {
+                write!(f, " + {auto_traits}")?;
+            }
+        }
+    }
+
+    let show_versions = match options.versions {
+        VersionDisplay::None => false,
+        VersionDisplay::TopLevel => top_level,
+        VersionDisplay::All => true,
+    };
+    let versions = [
+        info.crate_version().as_ref().map(|x| x.to_string()),
+        info.rustc_version().as_ref().map(|x| format!("rustc {x}")),
+    ];
+    let mut versions = versions.iter().flatten().filter(|_| show_versions);
+    if let Some(first) = versions.next() {
+        write!(f, " ({first}")?;
+        for version in versions {
+            write!(f, ", {version}")?;
+        }
+        f.write_char(')')?;
+    }
+    Ok(())
+}
+
+struct Row {
+    differs: bool,
+    columns: [String; 5],
+}
+
+fn node_row(info: &TypeInfo, label: String, differs: bool) -> Row {
+    let or_dash = |x: Option<String>| x.unwrap_or_else(|| "-".to_owned());
+    Row {
+        differs,
+        columns: [
+            label,
+            or_dash(info.crate_name().clone()),
+            or_dash(info.crate_module().clone()),
+            or_dash(info.crate_version().as_ref().map(|x| x.to_string())),
+            or_dash(info.rustc_version().as_ref().map(|x| format!("rustc {x}"))),
+        ],
+    }
+}
+
+fn same_node(lhs: &TypeInfo, rhs: &TypeInfo) -> bool {
+    lhs.type_name() == rhs.type_name()
+        && lhs.crate_name() == rhs.crate_name()
+        && lhs.crate_module() == rhs.crate_module()
+        && lhs.crate_version() == rhs.crate_version()
+        && lhs.rustc_version() == rhs.rustc_version()
+}
+
+/// Collects one row per type, 'compare' is set if a reference is given at the top level
+fn collect_rows(
+    info: &TypeInfo,
+    reference: Option<&TypeInfo>,
+    compare: bool,
+    prefix: &str,
+    child_prefix: &str,
+    rows: &mut Vec<Row>,
+) {
+    let differs = compare && !reference.is_some_and(|r| same_node(info, r));
+    rows.push(node_row(
+        info,
+        format!("{prefix}{}", info.type_name()),
+        differs,
+    ));
+    let reference_generics = reference.map(TypeInfo::generics).unwrap_or_default();
+    let count = info.generics().len().max(reference_generics.len());
+    for i in 0..count {
+        let (branch, continuation) = if i + 1 == count {
+            ("\u{2514}\u{2500} ", "   ")
+        } else {
+            ("\u{251c}\u{2500} ", "\u{2502}  ")
+        };
+        let prefix = format!("{child_prefix}{branch}");
+        match info.generics().get(i) {
+            Some(generic) => collect_rows(
+                generic,
+                reference_generics.get(i),
+                compare,
+                &prefix,
+                &format!("{child_prefix}{continuation}"),
+                rows,
+            ),
+            None => {
+                let missing = &reference_generics[i];
+                let label = format!("{prefix}(missing) {}", missing.type_name());
+                rows.push(node_row(missing, label, true));
+            }
+        }
+    }
+}
+
+impl Display for TypeInfoTree<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let compare = self.reference.is_some();
+        let mut rows = Vec::new();
+        collect_rows(self.info, self.reference, compare, "", "", &mut rows);
+        let mut widths = [0; 5];
+        for row in &rows {
+            for (width, column) in widths.iter_mut().zip(&row.columns) {
+                *width = (*width).max(column.chars().count());
+            }
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+            let mut line = String::new();
+            if compare {
+                line.push_str(if row.differs { "* " } else { "  " });
+            }
+            for (j, (column, width)) in row.columns.iter().zip(widths).enumerate() {
+                if j > 0 {
+                    line.push_str("  ");
+                }
+                line.push_str(column);
+                line.extend(std::iter::repeat_n(' ', width - column.chars().count()));
+            }
+            f.write_str(line.trim_end())?;
+        }
+        Ok(())
+    }
+}
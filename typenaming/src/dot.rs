@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::type_shape::collect_shapes;
+use crate::{Fields, TypeInfo, TypeShape};
+
+/// Renders the given types and all their generics as Graphviz DOT graph
+/// There is one node per distinct type, labelled by its Rust syntax, and an edge from each type to each of its generics
+/// Nodes are grouped into one cluster per crate and crate version, the given types are drawn bold
+/// Field edges are drawn by 'render_with_fields'
+pub fn render(infos: &[TypeInfo]) -> String {
+    let mut graph = Graph::default();
+    for info in infos {
+        let node = graph.add(info);
+        graph.roots.push(node);
+    }
+    graph.write()
+}
+
+/// Renders the given types like 'render', but additionally draws a dashed edge from each type with shape to the type of each of its fields
+/// Field edges are labelled by the field name or position, prefixed by the variant for enums, e.g. 'name', '0' or 'Admin.level'
+/// Types with shape, which are referenced by fields, are included recursively, the shape is returned by 'TypeNameable::type_shape'
+pub fn render_with_fields(types: &[(TypeInfo, Option<TypeShape>)]) -> String {
+    let shapes: Vec<(TypeInfo, TypeShape)> = types
+        .iter()
+        .flat_map(|(info, shape)| collect_shapes(info.clone(), shape.clone()))
+        .collect();
+    let mut graph = Graph::default();
+    for (info, _) in types {
+        let node = graph.add(info);
+        graph.roots.push(node);
+    }
+    for (info, shape) in &shapes {
+        let from = graph.add(info);
+        let fields: Vec<(Option<&str>, &Fields)> = match shape {
+            TypeShape::Struct(fields) => vec![(None, fields)],
+            TypeShape::Enum(variants) => variants
+                .iter()
+                .map(|variant| (Some(variant.name()), variant.fields()))
+                .collect(),
+        };
+        for (variant, fields) in fields {
+            for (i, field) in fields.iter().enumerate() {
+                let name = field.name().map_or_else(|| i.to_string(), str::to_owned);
+                let label = match variant {
+                    Some(variant) => format!("{variant}.{name}"),
+                    None => name,
+                };
+                let edge = (from, graph.add(field.type_info()), label);
+                if !graph.field_edges.contains(&edge) {
+                    graph.field_edges.push(edge);
+                }
+            }
+        }
+    }
+    graph.write()
+}
+
+/// This collects the distinct types in the order of their first appearance
+#[derive(Default)]
+struct Graph<'a> {
+    nodes: Vec<&'a TypeInfo>,
+    indices: HashMap<&'a TypeInfo, usize>,
+    clusters: Vec<Cluster<'a>>,
+    edges: Vec<(usize, usize)>,
+    field_edges: Vec<(usize, usize, String)>,
+    roots: Vec<usize>,
+}
+
+impl<'a> Graph<'a> {
+    /// Writes the DOT graph, the given types are drawn bold
+    fn write(mut self) -> String {
+        let mut out = String::from("digraph types {\n    node [shape=box];\n");
+        for (i, cluster) in self.clusters.iter().enumerate() {
+            let indent = match cluster.label() {
+                Some(label) => {
+                    writeln!(
+                        out,
+                        "    subgraph cluster_{i} {{\n        label={};",
+                        quote(&label)
+                    )
+                    .expect("Writing to a String cannot fail");
+                    "        "
+                }
+                None => "    ",
+            };
+            let nodes =
+                (0..self.nodes.len()).filter(|&node| cluster_of(self.nodes[node]) == *cluster);
+            for node in nodes {
+                let bold = if self.roots.contains(&node) {
+                    ", style=bold"
+                } else {
+                    ""
+                };
+                let label = quote(&self.nodes[node].to_string());
+                writeln!(out, "{indent}n{node} [label={label}{bold}];")
+                    .expect("Writing to a String cannot fail");
+            }
+            if cluster.label().is_some() {
+                out.push_str("    }\n");
+            }
+        }
+        self.edges.sort_unstable();
+        for (from, to) in &self.edges {
+            writeln!(out, "    n{from} -> n{to};").expect("Writing to a String cannot fail");
+        }
+        for (from, to, label) in &self.field_edges {
+            let label = quote(label);
+            writeln!(out, "    n{from} -> n{to} [label={label}, style=dashed];")
+                .expect("Writing to a String cannot fail");
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    fn add(&mut self, info: &'a TypeInfo) -> usize {
+        if let Some(&node) = self.indices.get(info) {
+            return node;
+        }
+        let node = self.nodes.len();
+        self.nodes.push(info);
+        self.indices.insert(info, node);
+        let cluster = cluster_of(info);
+        if !self.clusters.contains(&cluster) {
+            self.clusters.push(cluster);
+        }
+        for generic in info.generics() {
+            let edge = (node, self.add(generic));
+            if !self.edges.contains(&edge) {
+                self.edges.push(edge);
+            }
+        }
+        node
+    }
+}
+
+/// Types are clustered by crate and crate version, types without crate are not clustered
+#[derive(PartialEq)]
+struct Cluster<'a> {
+    crate_name: Option<&'a str>,
+    crate_version: Option<&'a semver::Version>,
+}
+
+impl Cluster<'_> {
+    fn label(&self) -> Option<String> {
+        let crate_name = self.crate_name?;
+        Some(match self.crate_version {
+            Some(version) => format!("{crate_name} {version}"),
+            None => crate_name.to_owned(),
+        })
+    }
+}
+
+fn cluster_of(info: &TypeInfo) -> Cluster<'_> {
+    Cluster {
+        crate_name: info.crate_name().as_deref(),
+        crate_version: info.crate_version().as_ref(),
+    }
+}
+
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::type_shape::{collect_shapes, declaration_names};
+use crate::{Field, Fields, TypeInfo, TypeNameable, TypeShape};
+
+/// This is the dialect of the generated schemas
+pub const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a JSON Schema for the given type, its '$id' is the type URL of the type without rustc version and extra entries
+/// Field and variant metadata is recorded by deriving TypeNameable with '#[typenameable(fields)]', referenced types with it are placed in '$defs', e.g. the generic argument 'User' of `Page<User>`
+/// Types without it are mapped structurally, e.g. `Vec<T>` to an array and `HashMap<K, V>` to an object, or are not restricted at all
+/// Definitions are named like the type, e.g. 'Page_User' for `Page<User>`, if two types share a name, both are prefixed by their module, e.g. 'api_Error'
+/// The schema describes serde's default JSON representation, i.e. enums are externally tagged and missing 'Option' fields are allowed, serde attributes like 'rename' or 'tag' are not taken into account
+pub fn schema<T: TypeNameable + ?Sized>() -> Value {
+    let info = T::type_info();
+    let shapes = collect_shapes(info.clone(), T::type_shape());
+    let generator = Generator {
+        shapes: &shapes,
+        names: declaration_names(&shapes),
+    };
+
+    let mut schema = Map::new();
+    schema.insert("$schema".to_owned(), json!(DRAFT));
+    schema.insert("$id".to_owned(), json!(schema_id(&info).type_url()));
+    schema.insert("title".to_owned(), json!(info.to_string()));
+    let root = match shapes.first() {
+        Some((_, shape)) => generator.shape_schema(shape),
+        None => generator.type_schema(&info),
+    };
+    schema.extend(root);
+    let defs: Map<String, Value> = shapes
+        .iter()
+        .skip(1)
+        .map(|(info, shape)| {
+            let mut def = Map::new();
+            def.insert("title".to_owned(), json!(info.to_string()));
+            def.extend(generator.shape_schema(shape));
+            (generator.names[info].clone(), Value::Object(def))
+        })
+        .collect();
+    if !defs.is_empty() {
+        schema.insert("$defs".to_owned(), Value::Object(defs));
+    }
+    Value::Object(schema)
+}
+
+/// The '$id' is independent of the compiler, hence rustc versions and extra entries are dropped
+fn schema_id(info: &TypeInfo) -> TypeInfo {
+    TypeInfo::new(
+        info.type_name().to_owned(),
+        info.crate_name().clone(),
+        info.crate_module().clone(),
+        info.crate_version().clone(),
+        None,
+        info.generics().iter().map(schema_id).collect(),
+    )
+}
+
+/// The first shape is the root, the others are placed in '$defs' under their unique declaration name
+struct Generator<'a> {
+    shapes: &'a [(TypeInfo, TypeShape)],
+    names: HashMap<TypeInfo, String>,
+}
+
+impl Generator<'_> {
+    fn shape_schema(&self, shape: &TypeShape) -> Map<String, Value> {
+        match shape {
+            TypeShape::Struct(fields) => self.fields_schema(fields),
+            TypeShape::Enum(variants) if variants.is_empty() => object(json!({ "not": {} })),
+            TypeShape::Enum(variants) => {
+                let variants: Vec<Value> = variants
+                    .iter()
+                    .map(|variant| match variant.fields() {
+                        Fields::Unit => json!({ "const": variant.name() }),
+                        fields => json!({
+                            "type": "object",
+                            "properties": { variant.name(): self.fields_schema(fields) },
+                            "required": [variant.name()],
+                            "additionalProperties": false,
+                        }),
+                    })
+                    .collect();
+                object(json!({ "oneOf": variants }))
+            }
+        }
+    }
+
+    fn fields_schema(&self, fields: &Fields) -> Map<String, Value> {
+        match fields {
+            Fields::Unit => object(json!({ "type": "null" })),
+            Fields::Named(fields) => {
+                let properties: Map<String, Value> = fields
+                    .iter()
+                    .map(|field| {
+                        let name = field.name().unwrap_or_default().to_owned();
+                        (name, Value::Object(self.type_schema(field.type_info())))
+                    })
+                    .collect();
+                // Serde treats missing 'Option' fields as 'None'
+                let required: Vec<&str> = fields
+                    .iter()
+                    .filter(|field| !is_option(field.type_info()))
+                    .filter_map(Field::name)
+                    .collect();
+                object(json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                }))
+            }
+            Fields::Unnamed(fields) if fields.len() == 1 => self.type_schema(fields[0].type_info()),
+            Fields::Unnamed(fields) => {
+                self.tuple_schema(fields.iter().map(|field| field.type_info()))
+            }
+        }
+    }
+
+    fn tuple_schema<'a>(&self, elements: impl Iterator<Item = &'a TypeInfo>) -> Map<String, Value> {
+        let elements: Vec<Map<String, Value>> =
+            elements.map(|element| self.type_schema(element)).collect();
+        object(json!({
+            "type": "array",
+            "prefixItems": elements,
+            "items": false,
+            "minItems": elements.len(),
+        }))
+    }
+
+    /// Maps a type to a schema, types with shape are referenced
+    fn type_schema(&self, info: &TypeInfo) -> Map<String, Value> {
+        match self.shapes.iter().position(|(shape, _)| shape == info) {
+            Some(0) => return object(json!({ "$ref": "#" })),
+            Some(_) => return object(json!({ "$ref": format!("#/$defs/{}", self.names[info]) })),
+            None => {}
+        }
+        let generic = |i: usize| match info.generics().get(i) {
+            Some(generic) => self.type_schema(generic),
+            None => Map::new(),
+        };
+        let is_std = matches!(info.crate_name().as_deref(), Some("core" | "alloc" | "std"));
+        match info.type_name() {
+            _ if !is_std => Map::new(),
+            "u8" => integer(0, u8::MAX),
+            "u16" => integer(0, u16::MAX),
+            "u32" => integer(0, u32::MAX),
+            "i8" => integer(i8::MIN, i8::MAX),
+            "i16" => integer(i16::MIN, i16::MAX),
+            "i32" => integer(i32::MIN, i32::MAX),
+            "u64" | "u128" | "usize" => object(json!({ "type": "integer", "minimum": 0 })),
+            "i64" | "i128" | "isize" => object(json!({ "type": "integer" })),
+            "f32" | "f64" => object(json!({ "type": "number" })),
+            "bool" => object(json!({ "type": "boolean" })),
+            "char" => object(json!({ "type": "string", "minLength": 1, "maxLength": 1 })),
+            "str" | "String" => object(json!({ "type": "string" })),
+            "()" | "PhantomData" => object(json!({ "type": "null" })),
+            "!" => object(json!({ "not": {} })),
+            "tuple" => self.tuple_schema(info.generics().iter()),
+            "array" => {
+                let mut schema = object(json!({ "type": "array", "items": generic(0) }));
+                let length = info.generics().get(1).map(TypeInfo::type_name);
+                if let Some(length) = length.and_then(|length| length.parse::<u64>().ok()) {
+                    schema.insert("minItems".to_owned(), json!(length));
+                    schema.insert("maxItems".to_owned(), json!(length));
+                }
+                schema
+            }
+            "slice" | "Vec" | "VecDeque" | "LinkedList" | "BinaryHeap" => {
+                object(json!({ "type": "array", "items": generic(0) }))
+            }
+            "BTreeSet" | "HashSet" => {
+                object(json!({ "type": "array", "items": generic(0), "uniqueItems": true }))
+            }
+            // Keys are written as JSON strings, hence only the values are described
+            "BTreeMap" | "HashMap" => {
+                object(json!({ "type": "object", "additionalProperties": generic(1) }))
+            }
+            "Option" => object(json!({ "anyOf": [generic(0), { "type": "null" }] })),
+            "Box" | "Rc" | "Arc" | "Cow" | "Cell" | "RefCell" | "Mutex" | "RwLock" => generic(0),
+            _ => Map::new(),
+        }
+    }
+}
+
+fn is_option(info: &TypeInfo) -> bool {
+    info.type_name() == "Option" && info.crate_module().as_deref() == Some("core::option")
+}
+
+fn integer(minimum: impl Into<i64>, maximum: impl Into<i64>) -> Map<String, Value> {
+    object(json!({
+        "type": "integer",
+        "minimum": minimum.into(),
+        "maximum": maximum.into(),
+    }))
+}
+
+fn object(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => unreachable!("Schemas are generated as JSON objects"),
+    }
+}
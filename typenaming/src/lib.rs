@@ -1,13 +1,57 @@
 #![deny(missing_docs)]
 //! This crate allows to derive the name of a type, together with some more basic information
+//!
+//! Function pointers with up to 12 arguments implement TypeNameable, unless their signature contains elided or higher-ranked lifetimes
+//! E.g. `fn(&str) -> bool` is short for `for<'a> fn(&'a str) -> bool`, which cannot be covered by the generic implementations
+//! ```compile_fail
+//! use typenaming::TypeNameable;
+//! <fn(&str) -> bool>::type_info();
+//! ```
+//! Naming the lifetime works, e.g. `fn(&'static str) -> bool`
+//! ```
+//! use typenaming::TypeNameable;
+//! assert_eq!("fn", <fn(&'static str) -> bool>::type_info().type_name());
+//! ```
 
+// This allows to use the derive macros within this crate
+extern crate self as typenaming;
+
+/// This module implements TypeNameable for some types of optional dependencies
+#[doc(hidden)]
+pub mod dependency_impl;
 /// This module implements TypeNameable for some standard types
 #[doc(hidden)]
 pub mod std_impl;
 
+mod binary;
+#[cfg(feature = "codegen")]
+mod codegen;
+mod display;
+/// This module renders TypeInfos as Graphviz DOT graph
+pub mod dot;
+/// This module generates JSON Schemas from derived field metadata
+#[cfg(feature = "json-schema")]
+pub mod json_schema;
+mod mangle;
+mod one_line;
+mod rust_type;
+/// This module allows to select the serde representation of a TypeInfo
+pub mod serde;
+/// This module generates TypeScript declarations from derived field metadata
+#[cfg(feature = "ts")]
+pub mod ts;
 mod type_info;
+mod type_info_ref;
+mod type_shape;
+mod type_url;
 mod typenameable;
-pub use crate::type_info::TypeInfo;
+pub use crate::binary::{DecodeError, DecodeErrorKind};
+#[cfg(feature = "codegen")]
+pub use crate::codegen::CodegenError;
+pub use crate::display::{DisplayOptions, TypeInfoDisplay, TypeInfoTree, VersionDisplay};
+pub use crate::type_info::{ExpectedToken, ParseError, ParseErrorKind, ParseOptions, TypeInfo};
+pub use crate::type_info_ref::TypeInfoRef;
+pub use crate::type_shape::{Field, Fields, TypeReference, TypeShape, Variant};
 pub use crate::typenameable::{TypeNameable, TypeQueryable};
 
 /// This is used by the derive macro, but shall not be used otherwise
@@ -17,7 +61,7 @@ pub use rustc_version::version as rustc_version;
 /// This represents a semver-Version.
 /// Re-Exported from semver [<https://docs.rs/semver>]
 pub use semver::Version;
-pub use typenaming_derive::{TypeNameable, TypeQueryable};
+pub use typenaming_derive::{nameable_trait, TypeNameable, TypeQueryable};
 
 /// This is a helper function which allows to easily produce a SemverVersion. It is used in the derived code.
 #[doc(hidden)]
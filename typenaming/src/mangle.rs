@@ -0,0 +1,520 @@
+//! Mangler and demangler of identifier-safe names, as generated by 'TypeInfo::mangled'
+//!
+//! The grammar of a mangled name is
+//!     mangled := '_t' format-version '_' type
+//!     type    := text [ 'c' text | 'nc' ] [ 'm' count text { text } ] [ 'v' version | 'nv' ] [ 'r' version | 'nr' ] { 'x' text text } [ 'g' count type { type } ]
+//!     text    := length '_' { lowercase | digit | '__' | '_u' lowercase | '_' hex-digits '_' }
+//!     version := major '_' minor '_' patch '_' [ 'p' text ] [ 'b' text ]
+//!     count   := number '_'
+//! The entries are the crate, the segments of the module, the crate version, the rustc version, the extra entries and the generics
+//! The length of a text counts the bytes of its encoded form, '__' denotes '_', '_u' an uppercase letter and '_…_' a character by its hexadecimal code point
+//! Hence mangled names only contain lowercase letters, digits and '_', so that they are distinct on case-insensitive file systems
+//! A generic without crate, crate version or rustc version inherits the value of the enclosing type, like in the relative one-line format
+//! 'nc', 'nv' and 'nr' then denote a missing value, so that the length grows with the number of types, but not with their metadata
+//! Since texts start with a digit and entries with a letter, no terminator is needed
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{ExpectedToken, ParseError, ParseErrorKind, ParseOptions, TypeInfo};
+
+const PREFIX: &str = "_t";
+
+/// Writes a mangled name, including the prefix
+pub(crate) fn write(info: &TypeInfo) -> String {
+    let mut out = format!("{PREFIX}{}_", TypeInfo::MANGLED_FORMAT_VERSION);
+    write_type(info, None, &mut out);
+    out
+}
+
+/// Writes a type, entries which are equal to those of the enclosing type 'parent' are omitted
+fn write_type(info: &TypeInfo, parent: Option<&TypeInfo>, out: &mut String) {
+    let inherited = |same: fn(&TypeInfo, &TypeInfo) -> bool| parent.is_some_and(|x| same(x, info));
+    write_text(info.type_name(), out);
+    match info.crate_name() {
+        _ if inherited(|x, y| x.crate_name() == y.crate_name()) => {}
+        Some(krate) => {
+            out.push('c');
+            write_text(krate, out);
+        }
+        None if parent.is_some() => out.push_str("nc"),
+        None => {}
+    }
+    if let Some(module) = info.crate_module() {
+        write!(out, "m{}_", module.split("::").count()).expect("Writing to a String cannot fail");
+        for segment in module.split("::") {
+            write_text(segment, out);
+        }
+    }
+    match info.crate_version() {
+        _ if inherited(|x, y| x.crate_version() == y.crate_version()) => {}
+        Some(version) => {
+            out.push('v');
+            write_version(version, out);
+        }
+        None if parent.is_some() => out.push_str("nv"),
+        None => {}
+    }
+    match info.rustc_version() {
+        _ if inherited(|x, y| x.rustc_version() == y.rustc_version()) => {}
+        Some(version) => {
+            out.push('r');
+            write_version(version, out);
+        }
+        None if parent.is_some() => out.push_str("nr"),
+        None => {}
+    }
+    for (key, value) in info.extra() {
+        out.push('x');
+        write_text(key, out);
+        write_text(value, out);
+    }
+    if !info.generics().is_empty() {
+        write!(out, "g{}_", info.generics().len()).expect("Writing to a String cannot fail");
+        for generic in info.generics() {
+            write_type(generic, Some(info), out);
+        }
+    }
+}
+
+fn write_text(text: &str, out: &mut String) {
+    let mut encoded = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            c if c.is_ascii_lowercase() || c.is_ascii_digit() => encoded.push(c),
+            '_' => encoded.push_str("__"),
+            c if c.is_ascii_uppercase() => {
+                encoded.push_str("_u");
+                encoded.push(c.to_ascii_lowercase());
+            }
+            c => write!(encoded, "_{:x}_", c as u32).expect("Writing to a String cannot fail"),
+        }
+    }
+    write!(out, "{}_{encoded}", encoded.len()).expect("Writing to a String cannot fail");
+}
+
+fn write_version(version: &semver::Version, out: &mut String) {
+    write!(
+        out,
+        "{}_{}_{}_",
+        version.major, version.minor, version.patch
+    )
+    .expect("Writing to a String cannot fail");
+    if !version.pre.is_empty() {
+        out.push('p');
+        write_text(version.pre.as_str(), out);
+    }
+    if !version.build.is_empty() {
+        out.push('b');
+        write_text(version.build.as_str(), out);
+    }
+}
+
+struct Demangler<'a> {
+    input: &'a str,
+    position: usize,
+    options: &'a ParseOptions,
+}
+
+impl Demangler<'_> {
+    fn error(&self, kind: ParseErrorKind, expected: &[ExpectedToken]) -> ParseError {
+        ParseError::new(kind, self.input, self.position, expected)
+    }
+
+    fn rest(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    fn eat(&mut self, tag: &str) -> bool {
+        let found = self.rest().starts_with(tag);
+        if found {
+            self.position += tag.len();
+        }
+        found
+    }
+
+    /// Reads a decimal number, which is terminated by '_'
+    fn number(&mut self) -> Result<u64, ParseError> {
+        let digits = self.rest().bytes().take_while(u8::is_ascii_digit).count();
+        let number = self.rest()[..digits]
+            .parse()
+            .map_err(|_| self.error(ParseErrorKind::UnexpectedData, &[]))?;
+        self.position += digits;
+        if !self.eat("_") {
+            return Err(self.error(ParseErrorKind::UnexpectedData, &[]));
+        }
+        Ok(number)
+    }
+
+    fn count(&mut self) -> Result<usize, ParseError> {
+        let start = self.position;
+        let count = self.number()?;
+        // Each item takes at least one byte, hence larger counts cannot be valid
+        usize::try_from(count)
+            .ok()
+            .filter(|&count| count <= self.rest().len())
+            .ok_or_else(|| ParseError::new(ParseErrorKind::UnexpectedData, self.input, start, &[]))
+    }
+
+    fn text(&mut self) -> Result<String, ParseError> {
+        let length = self.count()?;
+        let start = self.position;
+        let encoded = self
+            .rest()
+            .get(..length)
+            .ok_or_else(|| self.error(ParseErrorKind::InvalidEscape, &[]))?;
+        let invalid = |offset| {
+            ParseError::new(
+                ParseErrorKind::InvalidEscape,
+                self.input,
+                start + offset,
+                &[],
+            )
+        };
+        let mut text = String::with_capacity(length);
+        let mut i = 0;
+        while i < encoded.len() {
+            let c = encoded[i..].chars().next().expect("Index is below length");
+            let next = encoded[i + c.len_utf8()..].chars().next();
+            if c.is_ascii_lowercase() || c.is_ascii_digit() {
+                text.push(c);
+                i += 1;
+            } else if c != '_' {
+                return Err(invalid(i));
+            } else if next == Some('_') {
+                text.push('_');
+                i += 2;
+            } else if next == Some('u') {
+                let c = encoded[i + 2..]
+                    .chars()
+                    .next()
+                    .filter(char::is_ascii_lowercase)
+                    .ok_or_else(|| invalid(i))?;
+                text.push(c.to_ascii_uppercase());
+                i += 3;
+            } else {
+                let end = encoded[i + 1..]
+                    .find('_')
+                    .map(|end| i + 1 + end)
+                    .ok_or_else(|| invalid(i))?;
+                let c = u32::from_str_radix(&encoded[i + 1..end], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| invalid(i))?;
+                text.push(c);
+                i = end + 1;
+            }
+        }
+        self.position += length;
+        Ok(text)
+    }
+
+    fn version(
+        &mut self,
+        error: fn(semver::Error) -> ParseErrorKind,
+    ) -> Result<semver::Version, ParseError> {
+        let major = self.number()?;
+        let minor = self.number()?;
+        let patch = self.number()?;
+        let mut version = semver::Version::new(major, minor, patch);
+        let start = self.position;
+        let invalid = |e| ParseError::new(error(e), self.input, start, &[]);
+        if self.eat("p") {
+            version.pre = semver::Prerelease::new(&self.text()?).map_err(invalid)?;
+        }
+        if self.eat("b") {
+            version.build = semver::BuildMetadata::new(&self.text()?).map_err(invalid)?;
+        }
+        Ok(version)
+    }
+
+    /// Parses a type, missing entries are inherited from the enclosing type 'parent'
+    fn demangle_type(
+        &mut self,
+        depth: usize,
+        parent: Option<&TypeInfo>,
+    ) -> Result<TypeInfo, ParseError> {
+        if !self.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(self.error(ParseErrorKind::TypeNameNotFound, &[ExpectedToken::TypeName]));
+        }
+        let type_name = self.text()?;
+        let crate_name = if self.eat("c") {
+            Some(self.text()?)
+        } else if self.eat("nc") {
+            None
+        } else {
+            parent.and_then(|x| x.crate_name().clone())
+        };
+        let crate_module = if self.eat("m") {
+            let segments = (0..self.count()?)
+                .map(|_| self.text())
+                .collect::<Result<Vec<_>, _>>()?;
+            Some(segments.join("::"))
+        } else {
+            None
+        };
+        let crate_version = if self.eat("v") {
+            Some(self.version(ParseErrorKind::FailedToParseCrateVersionSemver)?)
+        } else if self.eat("nv") {
+            None
+        } else {
+            parent.and_then(|x| x.crate_version().clone())
+        };
+        let rustc_version = if self.eat("r") {
+            Some(self.version(ParseErrorKind::FailedToParseRustcVersionSemver)?)
+        } else if self.eat("nr") {
+            None
+        } else {
+            parent.and_then(|x| x.rustc_version().clone())
+        };
+        let mut extra = BTreeMap::new();
+        while self.eat("x") {
+            let start = self.position;
+            if extra.insert(self.text()?, self.text()?).is_some() {
+                return Err(ParseError::new(
+                    ParseErrorKind::ExtraSetTwice,
+                    self.input,
+                    start,
+                    &[],
+                ));
+            }
+        }
+        let mut generics = Vec::new();
+        if self.eat("g") {
+            if depth >= self.options.max_depth {
+                return Err(self.error(ParseErrorKind::NestingTooDeep, &[]));
+            }
+            // Only the inherited entries of the enclosing type are needed
+            let parent = TypeInfo::new(
+                String::new(),
+                crate_name.clone(),
+                None,
+                crate_version.clone(),
+                rustc_version.clone(),
+                Vec::new(),
+            );
+            for _ in 0..self.count()? {
+                generics.push(self.demangle_type(depth + 1, Some(&parent))?);
+            }
+        }
+        Ok(TypeInfo::new(
+            type_name,
+            crate_name,
+            crate_module,
+            crate_version,
+            rustc_version,
+            generics,
+        )
+        .with_extra(extra))
+    }
+}
+
+/// Parses a mangled name, including the prefix
+pub(crate) fn demangle(input: &str, options: &ParseOptions) -> Result<TypeInfo, ParseError> {
+    if input.len() > options.max_length {
+        let offset = (0..=options.max_length)
+            .rev()
+            .find(|&i| input.is_char_boundary(i))
+            .unwrap_or(0);
+        return Err(ParseError::new(
+            ParseErrorKind::InputTooLong,
+            input,
+            offset,
+            &[],
+        ));
+    }
+    let prefix = format!("{PREFIX}{}_", TypeInfo::MANGLED_FORMAT_VERSION);
+    let Some(rest) = input.strip_prefix(&prefix) else {
+        let kind = if input.starts_with(PREFIX) {
+            ParseErrorKind::UnsupportedFormatVersion
+        } else {
+            ParseErrorKind::MissingMangledPrefix
+        };
+        return Err(ParseError::new(kind, input, 0, &[]));
+    };
+    let mut demangler = Demangler {
+        input,
+        position: input.len() - rest.len(),
+        options,
+    };
+    let info = demangler.demangle_type(0, None)?;
+    if !demangler.rest().is_empty() {
+        return Err(demangler.error(
+            ParseErrorKind::RemainingToken(Box::new(info)),
+            &[ExpectedToken::End],
+        ));
+    }
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseErrorKind, TypeInfo, TypeNameable};
+
+    fn roundtrip(info: &TypeInfo) {
+        let mangled = info.mangled();
+        let allowed = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_';
+        assert!(mangled.chars().all(allowed), "{mangled}");
+        assert_eq!(*info, TypeInfo::demangle(&mangled).unwrap(), "{mangled}");
+    }
+
+    #[test]
+    fn mangled() {
+        let info = TypeInfo::new(
+            "Gen".to_owned(),
+            Some("my_crate".to_owned()),
+            Some("my_crate::types".to_owned()),
+            Some(semver::Version::new(1, 2, 3)),
+            None,
+            vec![TypeInfo::new_const_argument("3".to_owned())],
+        );
+        assert_eq!(
+            "_t1_5__ugenc9_my__cratem2_9_my__crate5_typesv1_2_3_g1_1_3ncnv",
+            info.mangled()
+        );
+        roundtrip(&info);
+    }
+
+    #[test]
+    fn inherited() {
+        let info = std::collections::HashMap::<String, Vec<u8>>::type_info();
+        let mangled = info.mangled();
+        let version = info.crate_version().as_ref().map(ToString::to_string);
+        assert!(mangled.len() < 200, "{mangled}");
+        if let Some(version) = version {
+            let version = version.replace('.', "_");
+            assert_eq!(
+                1,
+                mangled.matches(&format!("v{version}_")).count(),
+                "{mangled}"
+            );
+        }
+        roundtrip(&info);
+        let info = TypeInfo::new(
+            "A".to_owned(),
+            Some("a".to_owned()),
+            None,
+            Some(semver::Version::new(1, 0, 0)),
+            Some(semver::Version::new(1, 70, 0)),
+            vec![
+                TypeInfo::new("B".to_owned(), None, None, None, None, vec![]),
+                TypeInfo::new(
+                    "C".to_owned(),
+                    Some("a".to_owned()),
+                    None,
+                    Some(semver::Version::new(2, 0, 0)),
+                    Some(semver::Version::new(1, 70, 0)),
+                    vec![],
+                ),
+            ],
+        );
+        assert_eq!(
+            "_t1_3__uac1_av1_0_0_r1_70_0_g2_3__ubncnvnr3__ucv2_0_0_",
+            info.mangled()
+        );
+        roundtrip(&info);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        let named = |name: &str| {
+            TypeInfo::new(
+                name.to_owned(),
+                Some("krate".to_owned()),
+                None,
+                None,
+                None,
+                vec![],
+            )
+        };
+        let upper = named("Id").mangled();
+        let lower = named("id").mangled();
+        assert_eq!(upper, upper.to_lowercase());
+        assert_ne!(upper.to_lowercase(), lower.to_lowercase());
+        roundtrip(&named("Id"));
+        roundtrip(&named("ABC_Def"));
+    }
+
+    #[test]
+    fn roundtrips() {
+        roundtrip(&Vec::<Result<u8, String>>::type_info());
+        roundtrip(&<[Vec<u8>; 3]>::type_info());
+        roundtrip(&TypeInfo::from_rust_type_str("dyn core::any::Any + Send").unwrap());
+        roundtrip(&TypeInfo::from_rust_type_str("fn(u8) -> alloc::string::String").unwrap());
+        roundtrip(
+            &TypeInfo::new(
+                "T<ä>_1".to_owned(),
+                Some(String::new()),
+                Some(String::new()),
+                Some("1.0.0-alpha.1+build.5".parse().unwrap()),
+                Some(semver::Version::new(1, 70, 0)),
+                vec![TypeInfo::new(
+                    "12".to_owned(),
+                    None,
+                    Some("a::::b".to_owned()),
+                    None,
+                    None,
+                    vec![],
+                )],
+            )
+            .with_extra(
+                [("x", "g"), ("", ""), ("a;b", "c/d")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                    .collect(),
+            ),
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |s: &str| TypeInfo::demangle(s).unwrap_err();
+        assert!(matches!(
+            error("3_Vec").kind(),
+            ParseErrorKind::MissingMangledPrefix
+        ));
+        assert!(matches!(
+            error("_t2_3_vec").kind(),
+            ParseErrorKind::UnsupportedFormatVersion
+        ));
+        assert!(matches!(
+            error("_t1_c3_vec").kind(),
+            ParseErrorKind::TypeNameNotFound
+        ));
+        assert!(matches!(
+            error("_t1_3_vecg2_2_u8").kind(),
+            ParseErrorKind::TypeNameNotFound
+        ));
+        assert!(matches!(
+            error("_t1_3_vec_").kind(),
+            ParseErrorKind::RemainingToken(_)
+        ));
+        assert!(matches!(
+            error("_t1_3_v_x").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("_t1_3_vecv1_2_3_p2_01").kind(),
+            ParseErrorKind::FailedToParseCrateVersionSemver(_)
+        ));
+        assert!(matches!(
+            error("_t1_1_ax1_k1_vx1_k1_w").kind(),
+            ParseErrorKind::ExtraSetTwice
+        ));
+        assert!(matches!(
+            error("_t1_2_\u{e4}").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("_t1_3_Vec").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("_t1_2__u").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        let error = error("_t1_9_vec");
+        assert!(matches!(error.kind(), ParseErrorKind::UnexpectedData));
+        assert_eq!(4, error.offset());
+    }
+}
@@ -0,0 +1,870 @@
+//! Writer and parser of the one-line format, as generated by 'TypeInfo::to_one_line_string'
+//!
+//! The grammar of the format is
+//!     one_line := [ '@' version [ '+relative' ] whitespace ] type
+//!     type     := text [ '<' type { ';' type } '>' ] { ',' key '=' [ text ] }
+//!     key      := 'Crate' | 'Module' | 'Version' | 'Rustc' | text
+//!     text     := { character | escape }
+//!     escape   := '\' special | '\u{' hex-digits '}' | '\e'
+//!     special  := '\' | ',' | ';' | '<' | '>' | '=' | '@'
+//! Whitespace around texts and delimiters is ignored
+//! '\e' denotes an empty text, '\u{…}' is used for control characters and for whitespace at the start or end of a text
+//! If the version marker is missing, version 1 is assumed
+//! In relative mode, a generic without 'Crate', 'Version' or 'Rustc' entry inherits the value of the enclosing type
+//! An entry without text, e.g. 'Crate=', then denotes a missing value
+//! Unknown keys are kept as extra entries, unless parsing is strict
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::type_info_ref::RawVersion;
+use crate::{ExpectedToken, ParseError, ParseErrorKind, ParseOptions, TypeInfo, TypeInfoRef};
+
+const CRATE: &str = "Crate";
+const MODULE: &str = "Module";
+const VERSION: &str = "Version";
+const RUSTC: &str = "Rustc";
+/// This suffix of the format version marker selects the relative mode
+pub(crate) const RELATIVE: &str = "+relative";
+
+/// These flags of 'TypeInfoRef::inherit' mark entries, which are inherited from the enclosing type in relative mode
+pub(crate) const INHERIT_CRATE: u8 = 1;
+pub(crate) const INHERIT_VERSION: u8 = 2;
+pub(crate) const INHERIT_RUSTC: u8 = 4;
+
+/// Writes a TypeInfo in the one-line format, without version marker
+pub(crate) fn write(info: &TypeInfo, out: &mut String) {
+    write_type(info, false, None, out);
+}
+
+/// Writes a TypeInfo in the relative one-line format, without version marker
+pub(crate) fn write_relative(info: &TypeInfo, out: &mut String) {
+    write_type(info, true, None, out);
+}
+
+/// Writes a type, 'parent' is the enclosing type in relative mode
+fn write_type(info: &TypeInfo, relative: bool, parent: Option<&TypeInfo>, out: &mut String) {
+    escape(info.type_name(), out);
+    if let Some((first, others)) = info.generics().split_first() {
+        let parent = Some(info).filter(|_| relative);
+        out.push('<');
+        write_type(first, relative, parent, out);
+        for generic in others {
+            out.push_str("; ");
+            write_type(generic, relative, parent, out);
+        }
+        out.push('>');
+    }
+    let same_as_parent = |same: fn(&TypeInfo, &TypeInfo) -> bool| parent.map(|x| same(x, info));
+    let entries = [
+        (
+            CRATE,
+            info.crate_name().as_deref().map(Cow::Borrowed),
+            same_as_parent(|x, y| x.crate_name() == y.crate_name()),
+        ),
+        (
+            MODULE,
+            info.crate_module().as_deref().map(Cow::Borrowed),
+            None,
+        ),
+        (
+            VERSION,
+            info.crate_version().as_ref().map(|x| x.to_string().into()),
+            same_as_parent(|x, y| x.crate_version() == y.crate_version()),
+        ),
+        (
+            RUSTC,
+            info.rustc_version().as_ref().map(|x| x.to_string().into()),
+            same_as_parent(|x, y| x.rustc_version() == y.rustc_version()),
+        ),
+    ];
+    for (key, value, same_as_parent) in entries {
+        match (value, same_as_parent) {
+            (_, Some(true)) | (None, None) => {}
+            (Some(value), _) => {
+                out.push_str(", ");
+                out.push_str(key);
+                out.push('=');
+                escape(&value, out);
+            }
+            // The parent's value would be inherited, hence a missing value is written explicitly
+            (None, Some(false)) => {
+                out.push_str(", ");
+                out.push_str(key);
+                out.push('=');
+            }
+        }
+    }
+    for (key, value) in info.extra() {
+        out.push_str(", ");
+        if [CRATE, MODULE, VERSION, RUSTC].contains(&key.as_str()) {
+            // The first letter is escaped, so the entry is not mistaken for a known entry
+            let (first, rest) = key.split_at(1);
+            write!(out, "\\u{{{:x}}}", first.as_bytes()[0])
+                .expect("Writing to a String cannot fail");
+            escape(rest, out);
+        } else {
+            escape(key, out);
+        }
+        out.push('=');
+        escape(value, out);
+    }
+}
+
+fn escape(text: &str, out: &mut String) {
+    if text.is_empty() {
+        out.push_str("\\e");
+        return;
+    }
+    for (i, c) in text.char_indices() {
+        let at_edge = i == 0 || i + c.len_utf8() == text.len();
+        match c {
+            '\\' | ',' | ';' | '<' | '>' | '=' | '@' => {
+                out.push('\\');
+                out.push(c);
+            }
+            c if c.is_control() || (at_edge && c.is_whitespace()) => {
+                write!(out, "\\u{{{:x}}}", c as u32).expect("Writing to a String cannot fail")
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+fn unescape(text: &str) -> Result<Cow<'_, str>, ParseErrorKind> {
+    if !text.contains('\\') {
+        return Ok(Cow::Borrowed(text));
+    }
+    if text == "\\e" {
+        return Ok(Cow::Borrowed(""));
+    }
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(c @ ('\\' | ',' | ';' | '<' | '>' | '=' | '@')) => unescaped.push(c),
+            Some('u') if chars.next() == Some('{') => {
+                let hex = chars.by_ref().take_while(|&c| c != '}').collect::<String>();
+                let c = u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or(ParseErrorKind::InvalidEscape)?;
+                unescaped.push(c);
+            }
+            _ => return Err(ParseErrorKind::InvalidEscape),
+        }
+    }
+    Ok(Cow::Owned(unescaped))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    GenericStart,
+    GenericNext,
+    GenericEnd,
+    Comma,
+    Equals,
+}
+
+/// Splits the input into tokens, each of them is annotated with its byte offset
+struct Lexer<'a> {
+    input: &'a str,
+    position: usize,
+    peeked: Option<Option<(usize, Token<'a>)>>,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            position: 0,
+            peeked: None,
+        }
+    }
+
+    fn error(&self, kind: ParseErrorKind, offset: usize, expected: &[ExpectedToken]) -> ParseError {
+        ParseError::new(kind, self.input, offset, expected)
+    }
+
+    /// Reports an unexpected token at the given offset, or at the end of the input
+    fn unexpected(
+        &self,
+        kind: ParseErrorKind,
+        token: Option<(usize, Token)>,
+        expected: &[ExpectedToken],
+    ) -> ParseError {
+        let offset = token.map_or(self.input.len(), |(offset, _)| offset);
+        self.error(kind, offset, expected)
+    }
+
+    /// Skips the format version marker and reports, whether the relative mode is selected
+    fn format_version(&mut self) -> Result<bool, ParseError> {
+        let trimmed = self.input.trim_start();
+        let offset = self.input.len() - trimmed.len();
+        let Some(marker) = trimmed.strip_prefix('@') else {
+            return Ok(false);
+        };
+        let end = marker.find(char::is_whitespace).unwrap_or(marker.len());
+        let (version, relative) = match marker[..end].strip_suffix(RELATIVE) {
+            Some(version) => (version, true),
+            None => (&marker[..end], false),
+        };
+        match version.parse::<u32>() {
+            Ok(version) if version == TypeInfo::ONE_LINE_FORMAT_VERSION => {
+                self.position = offset + 1 + end;
+                Ok(relative)
+            }
+            _ => Err(self.error(ParseErrorKind::UnsupportedFormatVersion, offset, &[])),
+        }
+    }
+
+    fn peek(&mut self) -> Option<(usize, Token<'a>)> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.scan());
+        }
+        self.peeked.flatten()
+    }
+
+    fn next(&mut self) -> Option<(usize, Token<'a>)> {
+        let token = self.peek();
+        self.peeked = None;
+        token
+    }
+
+    fn scan(&mut self) -> Option<(usize, Token<'a>)> {
+        let rest = &self.input[self.position..];
+        let start = self.position + (rest.len() - rest.trim_start().len());
+        let mut chars = self.input[start..].char_indices();
+        let (_, first) = chars.next()?;
+        let token = match first {
+            '<' => Token::GenericStart,
+            ';' => Token::GenericNext,
+            '>' => Token::GenericEnd,
+            ',' => Token::Comma,
+            '=' => Token::Equals,
+            _ => {
+                let mut end = self.input.len() - start;
+                let mut escaped = first == '\\';
+                for (i, c) in chars {
+                    if escaped {
+                        escaped = false;
+                    } else if c == '\\' {
+                        escaped = true;
+                    } else if matches!(c, '<' | ';' | '>' | ',' | '=') {
+                        end = i;
+                        break;
+                    }
+                }
+                self.position = start + end;
+                return Some((
+                    start,
+                    Token::Text(self.input[start..start + end].trim_end()),
+                ));
+            }
+        };
+        self.position = start + 1;
+        Some((start, token))
+    }
+
+    fn unescape(&self, offset: usize, text: &'a str) -> Result<Cow<'a, str>, ParseError> {
+        unescape(text).map_err(|kind| self.error(kind, offset, &[]))
+    }
+}
+
+/// Parses a borrowed TypeInfo from the one-line format
+/// The parser keeps the partially parsed types on an explicit stack, hence deeply nested input cannot overflow the call stack
+pub(crate) fn parse<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<TypeInfoRef<'a>, ParseError> {
+    use ExpectedToken as E;
+    let mut lexer = Lexer::new(input);
+    if input.len() > options.max_length {
+        let offset = (0..=options.max_length)
+            .rev()
+            .find(|&i| input.is_char_boundary(i))
+            .unwrap_or(0);
+        return Err(lexer.error(ParseErrorKind::InputTooLong, offset, &[]));
+    }
+    let relative = lexer.format_version()?;
+
+    // Each entry is a type, whose list of generics is currently parsed
+    let mut stack: Vec<(Cow<str>, Vec<TypeInfoRef>)> = Vec::new();
+    loop {
+        let type_name = parse_type_name(&mut lexer)?;
+        if let Some((offset, Token::GenericStart)) = lexer.peek() {
+            if stack.len() >= options.max_depth {
+                return Err(lexer.error(ParseErrorKind::NestingTooDeep, offset, &[]));
+            }
+            lexer.next();
+            stack.push((type_name, Vec::new()));
+            continue;
+        }
+        let mut info = finish_type(
+            &mut lexer,
+            type_name,
+            Vec::new(),
+            !stack.is_empty(),
+            relative,
+            options.strict,
+        )?;
+        loop {
+            let Some((_, generics)) = stack.last_mut() else {
+                if relative {
+                    inherit(&mut info);
+                }
+                return Ok(info);
+            };
+            generics.push(info);
+            let expected = [E::GenericNext, E::GenericEnd];
+            match lexer.next() {
+                Some((_, Token::GenericNext)) => break,
+                Some((_, Token::GenericEnd)) => {
+                    let (type_name, generics) = stack.pop().expect("Stack is not empty");
+                    info = finish_type(
+                        &mut lexer,
+                        type_name,
+                        generics,
+                        !stack.is_empty(),
+                        relative,
+                        options.strict,
+                    )?;
+                }
+                None => {
+                    return Err(lexer.unexpected(
+                        ParseErrorKind::MissingGenericEnd,
+                        None,
+                        &expected,
+                    ))
+                }
+                token => {
+                    return Err(lexer.unexpected(ParseErrorKind::UnexpectedData, token, &expected))
+                }
+            }
+        }
+    }
+}
+
+fn parse_type_name<'a>(lexer: &mut Lexer<'a>) -> Result<Cow<'a, str>, ParseError> {
+    match lexer.next() {
+        Some((offset, Token::Text(text)))
+            if lexer.peek().map(|(_, t)| t) != Some(Token::Equals) =>
+        {
+            lexer.unescape(offset, text)
+        }
+        token => Err(lexer.unexpected(
+            ParseErrorKind::TypeNameNotFound,
+            token,
+            &[ExpectedToken::TypeName],
+        )),
+    }
+}
+
+/// Copies the inherited entries of the relative mode from the enclosing types, starting at the outermost type
+fn inherit(info: &mut TypeInfoRef) {
+    let mut stack = vec![info];
+    while let Some(parent) = stack.pop() {
+        let crate_name = parent.crate_name.clone();
+        let crate_version = parent.crate_version.clone();
+        let rustc_version = parent.rustc_version.clone();
+        for generic in parent.generics.iter_mut() {
+            if generic.inherit & INHERIT_CRATE != 0 {
+                generic.crate_name = crate_name.clone();
+            }
+            if generic.inherit & INHERIT_VERSION != 0 {
+                generic.crate_version = crate_version.clone();
+            }
+            if generic.inherit & INHERIT_RUSTC != 0 {
+                generic.rustc_version = rustc_version.clone();
+            }
+            generic.inherit = 0;
+            stack.push(generic);
+        }
+    }
+}
+
+/// Parses the entries, which follow the type name and the generics, and checks the following token
+fn finish_type<'a>(
+    lexer: &mut Lexer<'a>,
+    type_name: Cow<'a, str>,
+    generics: Vec<TypeInfoRef<'a>>,
+    nested: bool,
+    relative: bool,
+    strict: bool,
+) -> Result<TypeInfoRef<'a>, ParseError> {
+    use ExpectedToken as E;
+    let mut crate_name = None;
+    let mut crate_module = None;
+    let mut crate_version = None;
+    let mut rustc_version = None;
+    let mut extra = Vec::new();
+    while let Some((_, Token::Comma)) = lexer.peek() {
+        lexer.next();
+        let (offset, key) = match lexer.next() {
+            Some((offset, Token::Text(key))) => (offset, key),
+            token => {
+                let key = if strict { E::KnownKey } else { E::Key };
+                return Err(lexer.unexpected(ParseErrorKind::UnexpectedData, token, &[key]));
+            }
+        };
+        let known = match key {
+            CRATE => Some((
+                &mut crate_name,
+                ParseErrorKind::FailedToParseCrateName,
+                ParseErrorKind::CrateNameSetTwice,
+            )),
+            MODULE => Some((
+                &mut crate_module,
+                ParseErrorKind::FailedToParseCrateModule,
+                ParseErrorKind::CrateModuleSetTwice,
+            )),
+            VERSION => Some((
+                &mut crate_version,
+                ParseErrorKind::FailedToParseCrateVersion,
+                ParseErrorKind::CrateVersionSetTwice,
+            )),
+            RUSTC => Some((
+                &mut rustc_version,
+                ParseErrorKind::FailedToParseRustcVersion,
+                ParseErrorKind::RustcVersionSetTwice,
+            )),
+            _ if strict => {
+                return Err(lexer.error(ParseErrorKind::UnexpectedData, offset, &[E::KnownKey]))
+            }
+            _ => None,
+        };
+        match lexer.next() {
+            Some((_, Token::Equals)) => {}
+            token => {
+                let malformed = match known {
+                    Some((_, malformed, _)) => malformed,
+                    None => ParseErrorKind::UnexpectedData,
+                };
+                return Err(lexer.unexpected(malformed, token, &[E::Equals]));
+            }
+        }
+        let (value_offset, value) = match lexer.peek() {
+            Some((offset, Token::Text(value))) => {
+                lexer.next();
+                (offset, Some(lexer.unescape(offset, value)?))
+            }
+            // In relative mode, an inheritable entry without text denotes a missing value
+            token if relative && key != MODULE => {
+                (token.map_or(lexer.input.len(), |(offset, _)| offset), None)
+            }
+            token => (
+                token.map_or(lexer.input.len(), |(offset, _)| offset),
+                Some(Cow::Borrowed("")),
+            ),
+        };
+        match known {
+            Some((entry, _, set_twice)) => {
+                if entry.is_some() {
+                    return Err(lexer.error(set_twice, offset, &[]));
+                }
+                *entry = Some((value_offset, value));
+            }
+            None => {
+                let key = lexer.unescape(offset, key)?;
+                if extra.iter().any(|(k, _)| *k == key) {
+                    return Err(lexer.error(ParseErrorKind::ExtraSetTwice, offset, &[]));
+                }
+                extra.push((key, value.unwrap_or_default()));
+            }
+        }
+    }
+
+    let inherit = [
+        (INHERIT_CRATE, crate_name.is_none()),
+        (INHERIT_VERSION, crate_version.is_none()),
+        (INHERIT_RUSTC, rustc_version.is_none()),
+    ]
+    .iter()
+    .filter(|(_, missing)| relative && nested && *missing)
+    .fold(0, |inherit, (flag, _)| inherit | flag);
+    let raw_version = |version: Option<(usize, Option<Cow<'a, str>>)>| {
+        version.and_then(|(offset, text)| {
+            Some(RawVersion {
+                offset,
+                text: text?,
+            })
+        })
+    };
+    let info = TypeInfoRef {
+        input: lexer.input,
+        type_name,
+        crate_name: crate_name.and_then(|(_, x)| x),
+        crate_module: crate_module.and_then(|(_, x)| x),
+        crate_version: raw_version(crate_version),
+        rustc_version: raw_version(rustc_version),
+        generics,
+        extra,
+        inherit,
+    };
+
+    let token = lexer.peek();
+    let kind = match (token, nested) {
+        (None, _) | (Some((_, Token::GenericNext | Token::GenericEnd)), true) => return Ok(info),
+        (Some((_, Token::GenericStart)), _) => ParseErrorKind::UnexpectedGenericStart,
+        (Some((_, Token::GenericEnd)), false) => ParseErrorKind::UnexpectedGenericEnd,
+        (Some((_, Token::GenericNext)), false) => ParseErrorKind::UnexpectedGenericGenericNext,
+        (Some(_), false) => ParseErrorKind::RemainingToken(Box::new(info.to_owned()?)),
+        (Some(_), true) => ParseErrorKind::UnexpectedData,
+    };
+    let expected: &[E] = if nested {
+        &[E::Comma, E::GenericNext, E::GenericEnd]
+    } else {
+        &[E::Comma, E::End]
+    };
+    Err(lexer.unexpected(kind, token, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{ExpectedToken, ParseErrorKind, ParseOptions, TypeInfo, TypeInfoRef, TypeNameable};
+
+    fn roundtrip(info: &TypeInfo) {
+        let s = dbg!(info.to_one_line_string());
+        assert_eq!(&TypeInfo::from_str(&s).unwrap(), info);
+        let s = dbg!(info.to_versioned_one_line_string());
+        assert_eq!(&TypeInfo::from_str(&s).unwrap(), info);
+    }
+
+    fn named(type_name: &str, crate_module: Option<&str>, generics: Vec<TypeInfo>) -> TypeInfo {
+        TypeInfo::new(
+            type_name.to_owned(),
+            None,
+            crate_module.map(str::to_owned),
+            None,
+            None,
+            generics,
+        )
+    }
+
+    #[test]
+    fn partial_metadata_in_generics() {
+        let inner = TypeInfo::new(
+            "Test".to_owned(),
+            Some("testing".to_owned()),
+            None,
+            Some(semver::Version::new(0, 1, 0)),
+            None,
+            vec![],
+        );
+        roundtrip(&named(
+            "Gen",
+            None,
+            vec![inner.clone(), named("u8", None, vec![])],
+        ));
+        roundtrip(&named(
+            "Gen",
+            Some("m"),
+            vec![named("Gen", None, vec![inner])],
+        ));
+    }
+
+    #[test]
+    fn special_characters() {
+        roundtrip(&named(
+            "a,b;c<d>e=f\\g@h",
+            Some("Version=1.0.0, Crate=x"),
+            vec![named("@", Some(">"), vec![])],
+        ));
+        roundtrip(&named("dyn Plugin + Send", Some("Crate="), vec![]));
+        assert_eq!(
+            "a\\<b\\>, Module=Version\\=1",
+            named("a<b>", Some("Version=1"), vec![]).to_one_line_string()
+        );
+    }
+
+    #[test]
+    fn whitespace_and_empty_texts() {
+        roundtrip(&named("", Some(""), vec![named(" ", None, vec![])]));
+        roundtrip(&named(" padded\t", Some("line\nbreak"), vec![]));
+        roundtrip(&named("inner  space", Some("\u{2003}"), vec![]));
+        assert_eq!(
+            "\\e, Module=\\u{20}x\\u{20}",
+            named("", Some(" x "), vec![]).to_one_line_string()
+        );
+    }
+
+    #[test]
+    fn format_version_marker() {
+        let info = named("Vec", Some("alloc::vec"), vec![named("u8", None, vec![])]);
+        assert_eq!(
+            "@1 Vec<u8>, Module=alloc::vec",
+            info.to_versioned_one_line_string()
+        );
+        assert_eq!(
+            info,
+            TypeInfo::from_str("Vec<u8>, Module=alloc::vec").unwrap()
+        );
+        assert!(matches!(
+            TypeInfo::from_str("@2 Vec<u8>").unwrap_err().kind(),
+            ParseErrorKind::UnsupportedFormatVersion
+        ));
+    }
+
+    #[test]
+    fn malformed_input() {
+        let error = |s: &str| TypeInfo::from_str(s).unwrap_err();
+        assert!(matches!(
+            error("a\\b").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("a\\u{110000}").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("Vec<u8").kind(),
+            ParseErrorKind::MissingGenericEnd
+        ));
+        assert!(matches!(
+            error("Vec<u8>>").kind(),
+            ParseErrorKind::UnexpectedGenericEnd
+        ));
+        assert!(matches!(
+            error("u8; u16").kind(),
+            ParseErrorKind::UnexpectedGenericGenericNext
+        ));
+        assert!(matches!(
+            error("Crate=x").kind(),
+            ParseErrorKind::TypeNameNotFound
+        ));
+        assert!(matches!(
+            error("a, Crate=x, Crate=y").kind(),
+            ParseErrorKind::CrateNameSetTwice
+        ));
+        assert!(matches!(
+            error("a, Crate").kind(),
+            ParseErrorKind::FailedToParseCrateName
+        ));
+        assert!(matches!(
+            error("a, Colour=red, Colour=blue").kind(),
+            ParseErrorKind::ExtraSetTwice
+        ));
+        assert!(matches!(
+            error("a, Crate=x<u8>").kind(),
+            ParseErrorKind::UnexpectedGenericStart
+        ));
+        assert!(matches!(
+            error("a, Version=one").kind(),
+            ParseErrorKind::FailedToParseCrateVersionSemver(_)
+        ));
+    }
+
+    fn strict() -> ParseOptions {
+        ParseOptions {
+            strict: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn error_position() {
+        let error = TypeInfo::from_str("Vec<u8, Crate=core").unwrap_err();
+        assert_eq!(18, error.offset());
+        assert_eq!(
+            &[ExpectedToken::GenericNext, ExpectedToken::GenericEnd],
+            error.expected()
+        );
+        assert_eq!(
+            "missing '>' at byte 18, expected ';' or '>'",
+            error.to_string()
+        );
+
+        let error = TypeInfo::from_str_with_options("Vec<u8>, Colour=red", &strict()).unwrap_err();
+        assert_eq!(9, error.offset());
+        assert_eq!(&[ExpectedToken::KnownKey], error.expected());
+        assert_eq!("Vec<u8>, Colour=red", error.input());
+        let error = TypeInfo::from_str("Vec<u8>, <").unwrap_err();
+        assert_eq!(&[ExpectedToken::Key], error.expected());
+        assert_eq!(
+            "unexpected data at byte 9, expected entry key",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn error_rendering() {
+        let error = TypeInfo::from_str("Vec<u8>; u16").unwrap_err();
+        assert_eq!(
+            "Vec<u8>; u16\n       ^ unexpected ';' at byte 7, expected ',' or end of input",
+            error.render_caret()
+        );
+        let error = TypeInfo::from_str_with_options("Gen<a,\nb=c>", &strict()).unwrap_err();
+        assert_eq!(
+            "b=c>\n^ unexpected data at byte 7, expected 'Crate', 'Module', 'Version' or 'Rustc'",
+            error.render_caret()
+        );
+    }
+
+    #[test]
+    fn error_source() {
+        use std::error::Error;
+        let error = TypeInfo::from_str("a, Rustc=1.x").unwrap_err();
+        assert_eq!(9, error.offset());
+        assert!(error.source().unwrap().is::<semver::Error>());
+        assert!(TypeInfo::from_str("<").unwrap_err().source().is_none());
+    }
+
+    fn nested(depth: usize) -> String {
+        "A<".repeat(depth) + "u8" + &">".repeat(depth)
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let options = ParseOptions {
+            max_depth: 3,
+            ..Default::default()
+        };
+        assert!(TypeInfo::from_str_with_options(&nested(3), &options).is_ok());
+        let error = TypeInfo::from_str_with_options(&nested(4), &options).unwrap_err();
+        assert!(matches!(error.kind(), ParseErrorKind::NestingTooDeep));
+        assert_eq!(7, error.offset());
+
+        let default_depth = ParseOptions::default().max_depth;
+        assert!(TypeInfo::from_str(&nested(default_depth)).is_ok());
+        assert!(matches!(
+            TypeInfo::from_str(&nested(100_000)).unwrap_err().kind(),
+            ParseErrorKind::NestingTooDeep
+        ));
+    }
+
+    #[test]
+    fn length_limit() {
+        let options = ParseOptions {
+            max_length: 8,
+            ..Default::default()
+        };
+        assert!(TypeInfo::from_str_with_options("Vec<u8>", &options).is_ok());
+        let error =
+            TypeInfo::from_str_with_options("Vec<\u{e4}\u{e4}\u{e4}>", &options).unwrap_err();
+        assert!(matches!(error.kind(), ParseErrorKind::InputTooLong));
+        assert_eq!(8, error.offset());
+        let error = TypeInfo::from_str_with_options("Vec<u\u{e4}\u{e4}>", &options).unwrap_err();
+        assert_eq!(7, error.offset());
+    }
+
+    #[test]
+    fn unknown_entries() {
+        let s = "Vec<u8, Colour=red>, Crate=alloc, Size=24, Weight=\\e";
+        let info = TypeInfo::from_str(s).unwrap();
+        assert_eq!(Some("alloc"), info.crate_name().as_deref());
+        assert_eq!(Some("24"), info.extra().get("Size").map(String::as_str));
+        assert_eq!(Some(""), info.extra().get("Weight").map(String::as_str));
+        assert_eq!(
+            Some("red"),
+            info.generics()[0].extra().get("Colour").map(String::as_str)
+        );
+        assert_eq!(s, info.to_one_line_string());
+
+        let error = TypeInfo::from_str_with_options(s, &strict()).unwrap_err();
+        assert!(matches!(error.kind(), ParseErrorKind::UnexpectedData));
+        assert_eq!(8, error.offset());
+    }
+
+    #[test]
+    fn unknown_entries_roundtrip() {
+        let extra = [("Crate", "x"), ("", ""), ("a=b", "c,d"), ("Module", " ")]
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+        let info = named("T", Some("m"), vec![]).with_extra(extra);
+        roundtrip(&info);
+        assert!(TypeInfo::from_str_with_options(&info.to_one_line_string(), &strict()).is_err());
+    }
+
+    fn versioned(type_name: &str, crate_name: Option<&str>, generics: Vec<TypeInfo>) -> TypeInfo {
+        TypeInfo::new(
+            type_name.to_owned(),
+            crate_name.map(str::to_owned),
+            crate_name.map(|x| format!("{x}::types")),
+            crate_name.map(|_| semver::Version::new(1, 2, 3)),
+            Some(semver::Version::new(1, 70, 0)),
+            generics,
+        )
+    }
+
+    #[test]
+    fn relative() {
+        let test = || versioned("Test", Some("testing"), vec![]);
+        let info = versioned(
+            "Gen",
+            Some("testing"),
+            vec![
+                test(),
+                versioned("Gen", Some("testing"), vec![test()]),
+                test(),
+            ],
+        );
+        let s = info.to_relative_one_line_string();
+        assert_eq!(
+            "@1+relative Gen<Test, Module=testing::types; \
+             Gen<Test, Module=testing::types>, Module=testing::types; \
+             Test, Module=testing::types>, \
+             Crate=testing, Module=testing::types, Version=1.2.3, Rustc=1.70.0",
+            s
+        );
+        assert!(s.len() * 3 < info.to_one_line_string().len() * 2);
+        assert_eq!(info, TypeInfo::from_str(&s).unwrap());
+        assert_eq!(info, TypeInfoRef::parse(&s).unwrap());
+    }
+
+    #[test]
+    fn relative_missing_entries() {
+        let info = versioned(
+            "Gen",
+            Some("testing"),
+            vec![
+                versioned("Gen", None, vec![versioned("Test", Some("other"), vec![])]),
+                TypeInfo::new_const_argument("3".to_owned()),
+                versioned("Test", Some(""), vec![]),
+            ],
+        );
+        let s = info.to_relative_one_line_string();
+        assert!(s.contains(
+            "Gen<Test, Crate=other, Module=other::types, Version=1.2.3>, Crate=, Version=;"
+        ));
+        assert!(s.contains("; 3, Crate=, Version=, Rustc=;"));
+        assert_eq!(info, TypeInfo::from_str(&s).unwrap());
+        // Without marker, entries are not inherited
+        let s = "Gen<Test>, Crate=testing";
+        assert_eq!(
+            None,
+            TypeInfo::from_str(s).unwrap().generics()[0]
+                .crate_name()
+                .as_deref()
+        );
+        let relative = TypeInfo::from_str(&format!("@1+relative {s}")).unwrap();
+        assert_eq!(
+            Some("testing"),
+            relative.generics()[0].crate_name().as_deref()
+        );
+        assert!(matches!(
+            TypeInfo::from_str("@1+absolute Test").unwrap_err().kind(),
+            ParseErrorKind::UnsupportedFormatVersion
+        ));
+    }
+
+    #[test]
+    fn relative_roundtrip() {
+        let infos = [
+            Vec::<Result<u8, String>>::type_info(),
+            <[Vec<u8>; 3]>::type_info(),
+            TypeInfo::from_rust_type_str("fn(u8) -> alloc::string::String").unwrap(),
+        ];
+        for info in infos {
+            let s = info.to_relative_one_line_string();
+            assert_eq!(info, TypeInfo::from_str(&s).unwrap());
+        }
+    }
+}
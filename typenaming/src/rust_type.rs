@@ -0,0 +1,509 @@
+//! Parser for Rust type syntax, as generated by 'std::any::type_name'
+//!
+//! Types are described in the same way as by the TypeNameable implementations of this crate
+//! - paths give crate and module, e.g. `alloc::vec::Vec<u8>` is 'Vec' from crate 'alloc' and module 'alloc::vec'
+//! - private modules of the standard library are replaced by their public module, e.g. 'core::num::error' by 'core::num'
+//! - references are transparent, e.g. '&mut str' is described as 'str'
+//! - primitives, arrays, slices, tuples, pointers and function pointers are located in 'core::primitive'
+//! - trait objects are named like 'dyn Trait + Send', lifetimes are skipped
+
+use crate::{ExpectedToken, ParseError, ParseErrorKind, ParseOptions, TypeInfo};
+
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "str", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+    "u128", "usize", "f16", "f32", "f64", "f128", "!",
+];
+const STD_CRATES: &[&str] = &["core", "alloc", "std"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Literal(&'a str),
+    Lifetime,
+    PathSep,
+    Lt,
+    Gt,
+    Comma,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Semi,
+    And,
+    Star,
+    Plus,
+    Eq,
+    Arrow,
+    Bang,
+}
+
+fn is_ident_char(c: char) -> bool {
+    // Braces are part of names like '{{closure}}'
+    c.is_alphanumeric() || matches!(c, '_' | '{' | '}')
+}
+
+/// Splits the input into tokens in a single pass, each of them is annotated with its byte offset
+fn tokenize(input: &str) -> Result<Vec<(usize, Token<'_>)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    let end_of = |chars: &mut std::iter::Peekable<std::str::CharIndices>,
+                  predicate: &dyn Fn(char) -> bool| {
+        while chars.next_if(|&(_, c)| predicate(c)).is_some() {}
+        chars.peek().map_or(input.len(), |&(i, _)| i)
+    };
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            ':' if chars.next_if(|&(_, c)| c == ':').is_some() => Token::PathSep,
+            '<' => Token::Lt,
+            '>' => Token::Gt,
+            ',' => Token::Comma,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ';' => Token::Semi,
+            '&' => Token::And,
+            '*' => Token::Star,
+            '+' => Token::Plus,
+            '=' => Token::Eq,
+            '!' => Token::Bang,
+            '-' if chars.next_if(|&(_, c)| c == '>').is_some() => Token::Arrow,
+            '-' | '0'..='9' => {
+                let end = end_of(&mut chars, &|c| c.is_alphanumeric() || c == '_');
+                Token::Literal(&input[start..end])
+            }
+            '"' => {
+                let end = end_of(&mut chars, &|c| c != '"');
+                chars.next();
+                Token::Literal(&input[start..(end + 1).min(input.len())])
+            }
+            '\'' => {
+                let end = end_of(&mut chars, &is_ident_char);
+                if chars.next_if(|&(_, c)| c == '\'').is_some() {
+                    Token::Literal(&input[start..end + 1])
+                } else if end == start + 1 {
+                    // A char literal, which is not an identifier character, e.g. '-'
+                    chars.next();
+                    let end = end_of(&mut chars, &|c| c != '\'');
+                    chars.next();
+                    Token::Literal(&input[start..(end + 1).min(input.len())])
+                } else {
+                    Token::Lifetime
+                }
+            }
+            c if is_ident_char(c) => {
+                let end = end_of(&mut chars, &is_ident_char);
+                Token::Ident(&input[start..end])
+            }
+            _ => {
+                return Err(ParseError::new(
+                    ParseErrorKind::UnexpectedData,
+                    input,
+                    start,
+                    &[],
+                ))
+            }
+        };
+        tokens.push((start, token));
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(usize, Token<'a>)>,
+    position: usize,
+    max_depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).map(|&(_, t)| t)
+    }
+
+    fn peek_second(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position + 1).map(|&(_, t)| t)
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .map_or(self.input.len(), |&(offset, _)| offset)
+    }
+
+    fn error(&self, kind: ParseErrorKind, expected: &[ExpectedToken]) -> ParseError {
+        ParseError::new(kind, self.input, self.offset(), expected)
+    }
+
+    fn expect(&mut self, token: Token, expected: ExpectedToken) -> Result<(), ParseError> {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error(ParseErrorKind::UnexpectedData, &[expected]))
+        }
+    }
+
+    fn parse_type(&mut self, depth: usize) -> Result<TypeInfo, ParseError> {
+        if depth > self.max_depth {
+            return Err(self.error(ParseErrorKind::NestingTooDeep, &[]));
+        }
+        let depth = depth + 1;
+        match self.peek() {
+            Some(Token::And) => {
+                self.next();
+                if self.peek() == Some(Token::Lifetime) {
+                    self.next();
+                }
+                if self.peek() == Some(Token::Ident("mut")) {
+                    self.next();
+                }
+                self.parse_type(depth)
+            }
+            Some(Token::Star) => {
+                self.next();
+                let name = match self.next() {
+                    Some(Token::Ident("const")) => "*const",
+                    Some(Token::Ident("mut")) => "*mut",
+                    _ => {
+                        self.position -= 1;
+                        return Err(self.error(ParseErrorKind::UnexpectedData, &[]));
+                    }
+                };
+                Ok(primitive(name, vec![self.parse_type(depth)?]))
+            }
+            Some(Token::LParen) => {
+                self.next();
+                let (mut elements, trailing_comma) = self.parse_list(Token::RParen, depth)?;
+                match elements.len() {
+                    0 => Ok(primitive("()", vec![])),
+                    1 if !trailing_comma => Ok(elements.remove(0)),
+                    _ => Ok(primitive("tuple", elements)),
+                }
+            }
+            Some(Token::LBracket) => {
+                self.next();
+                let element = self.parse_type(depth)?;
+                if self.peek() == Some(Token::Semi) {
+                    self.next();
+                    let length = match self.next() {
+                        Some(Token::Literal(length)) => length,
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error(ParseErrorKind::UnexpectedData, &[]));
+                        }
+                    };
+                    self.expect(Token::RBracket, ExpectedToken::GenericEnd)?;
+                    let length = TypeInfo::new_const_argument(length.to_owned());
+                    Ok(primitive("array", vec![element, length]))
+                } else {
+                    self.expect(Token::RBracket, ExpectedToken::GenericEnd)?;
+                    Ok(primitive("slice", vec![element]))
+                }
+            }
+            Some(Token::Bang) => {
+                self.next();
+                Ok(primitive("!", vec![]))
+            }
+            Some(Token::Ident("dyn")) => {
+                self.next();
+                self.parse_trait_object(depth)
+            }
+            Some(Token::Ident("unsafe" | "extern" | "fn" | "for")) => {
+                self.parse_function_pointer(depth)
+            }
+            Some(Token::Ident(_) | Token::PathSep) => {
+                let (segments, generics) = self.parse_path(depth)?;
+                let name = segments.last().expect("Paths are never empty").to_string();
+                Ok(path_info(&segments, name, generics))
+            }
+            _ => Err(self.error(ParseErrorKind::TypeNameNotFound, &[ExpectedToken::TypeName])),
+        }
+    }
+
+    /// Parses types, which are separated by ',', until the given closing token
+    /// The second entry of the result is true, if there is a trailing ','
+    fn parse_list(
+        &mut self,
+        close: Token,
+        depth: usize,
+    ) -> Result<(Vec<TypeInfo>, bool), ParseError> {
+        let mut elements = Vec::new();
+        let mut trailing_comma = false;
+        while self.peek() != Some(close) {
+            elements.push(self.parse_type(depth)?);
+            trailing_comma = self.peek() == Some(Token::Comma);
+            if trailing_comma {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        self.expect(close, ExpectedToken::GenericEnd)?;
+        Ok((elements, trailing_comma))
+    }
+
+    /// Parses a path, e.g. 'alloc::vec::Vec<u8>' or 'Fn(u8) -> bool'
+    fn parse_path(&mut self, depth: usize) -> Result<(Vec<&'a str>, Vec<TypeInfo>), ParseError> {
+        if self.peek() == Some(Token::PathSep) {
+            self.next();
+        }
+        let mut segments = Vec::new();
+        loop {
+            match self.next() {
+                Some(Token::Ident(segment)) => segments.push(segment),
+                _ => {
+                    self.position -= 1;
+                    return Err(
+                        self.error(ParseErrorKind::TypeNameNotFound, &[ExpectedToken::TypeName])
+                    );
+                }
+            }
+            if self.peek() == Some(Token::PathSep) && self.peek_second() != Some(Token::Lt) {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        if self.peek() == Some(Token::PathSep) {
+            self.next();
+        }
+        let generics = match self.peek() {
+            Some(Token::Lt) => {
+                self.next();
+                self.parse_generic_arguments(depth)?
+            }
+            Some(Token::LParen) => {
+                self.next();
+                let (mut generics, _) = self.parse_list(Token::RParen, depth)?;
+                generics.push(self.parse_output(depth)?);
+                generics
+            }
+            _ => Vec::new(),
+        };
+        Ok((segments, generics))
+    }
+
+    fn parse_generic_arguments(&mut self, depth: usize) -> Result<Vec<TypeInfo>, ParseError> {
+        let mut generics = Vec::new();
+        loop {
+            match (self.peek(), self.peek_second()) {
+                (Some(Token::Gt), _) => break,
+                (Some(Token::Lifetime), _) => {
+                    self.next();
+                }
+                (Some(Token::Literal(value)), _) => {
+                    self.next();
+                    generics.push(TypeInfo::new_const_argument(value.to_owned()));
+                }
+                (Some(Token::Ident(value @ ("true" | "false"))), _) => {
+                    self.next();
+                    generics.push(TypeInfo::new_const_argument(value.to_owned()));
+                }
+                (Some(Token::Ident(_)), Some(Token::Eq)) => {
+                    // Associated types, e.g. 'Iterator<Item = u8>', are stored as generics
+                    self.next();
+                    self.next();
+                    generics.push(self.parse_type(depth)?);
+                }
+                (None, _) => {
+                    return Err(self.error(
+                        ParseErrorKind::MissingGenericEnd,
+                        &[ExpectedToken::GenericEnd],
+                    ))
+                }
+                _ => generics.push(self.parse_type(depth)?),
+            }
+            match self.peek() {
+                Some(Token::Comma) => {
+                    self.next();
+                }
+                Some(Token::Gt) => break,
+                None => {
+                    return Err(self.error(
+                        ParseErrorKind::MissingGenericEnd,
+                        &[ExpectedToken::GenericEnd],
+                    ))
+                }
+                _ => {
+                    return Err(
+                        self.error(ParseErrorKind::UnexpectedData, &[ExpectedToken::GenericEnd])
+                    )
+                }
+            }
+        }
+        self.next();
+        Ok(generics)
+    }
+
+    /// Parses an optional '-> Type', a missing return type is '()'
+    fn parse_output(&mut self, depth: usize) -> Result<TypeInfo, ParseError> {
+        if self.peek() == Some(Token::Arrow) {
+            self.next();
+            self.parse_type(depth)
+        } else {
+            Ok(primitive("()", vec![]))
+        }
+    }
+
+    /// Skips higher-ranked lifetimes, e.g. "for<'a>"
+    fn skip_for_lifetimes(&mut self) -> Result<(), ParseError> {
+        if self.peek() == Some(Token::Ident("for")) {
+            self.next();
+            self.expect(Token::Lt, ExpectedToken::GenericStart)?;
+            while let Some(Token::Lifetime | Token::Comma) = self.peek() {
+                self.next();
+            }
+            self.expect(Token::Gt, ExpectedToken::GenericEnd)?;
+        }
+        Ok(())
+    }
+
+    fn parse_trait_object(&mut self, depth: usize) -> Result<TypeInfo, ParseError> {
+        let mut principal = None;
+        let mut send = false;
+        let mut sync = false;
+        loop {
+            if self.peek() == Some(Token::Lifetime) {
+                self.next();
+            } else {
+                self.skip_for_lifetimes()?;
+                let (segments, generics) = self.parse_path(depth)?;
+                match segments.last() {
+                    Some(&"Send") if generics.is_empty() => send = true,
+                    Some(&"Sync") if generics.is_empty() => sync = true,
+                    _ if principal.is_none() => principal = Some((segments, generics)),
+                    _ => return Err(self.error(ParseErrorKind::UnexpectedData, &[])),
+                }
+            }
+            if self.peek() == Some(Token::Plus) {
+                self.next();
+            } else {
+                break;
+            }
+        }
+        let auto_traits = match (send, sync) {
+            (false, false) => "",
+            (true, false) => " + Send",
+            (false, true) => " + Sync",
+            (true, true) => " + Send + Sync",
+        };
+        Ok(match principal {
+            Some((segments, generics)) => {
+                let name = segments.last().expect("Paths are never empty");
+                path_info(&segments, format!("dyn {name}{auto_traits}"), generics)
+            }
+            None => TypeInfo::new(
+                format!("dyn{}", auto_traits.trim_start_matches(" +")),
+                None,
+                None,
+                None,
+                None,
+                vec![],
+            ),
+        })
+    }
+
+    fn parse_function_pointer(&mut self, depth: usize) -> Result<TypeInfo, ParseError> {
+        self.skip_for_lifetimes()?;
+        let mut kind = Vec::new();
+        if self.peek() == Some(Token::Ident("unsafe")) {
+            self.next();
+            kind.push("unsafe");
+        }
+        if self.peek() == Some(Token::Ident("extern")) {
+            self.next();
+            kind.push("extern");
+            if let Some(Token::Literal(abi)) = self.peek() {
+                self.next();
+                kind.push(abi);
+            }
+        }
+        self.expect(Token::Ident("fn"), ExpectedToken::TypeName)?;
+        kind.push("fn");
+        self.expect(Token::LParen, ExpectedToken::GenericStart)?;
+        let (mut generics, _) = self.parse_list(Token::RParen, depth)?;
+        generics.push(self.parse_output(depth)?);
+        Ok(primitive(&kind.join(" "), generics))
+    }
+}
+
+fn primitive(name: &str, generics: Vec<TypeInfo>) -> TypeInfo {
+    TypeInfo::new(
+        name.to_owned(),
+        Some("core".to_owned()),
+        Some("core::primitive".to_owned()),
+        crate::std_impl::std_version(),
+        crate::std_impl::std_version(),
+        generics,
+    )
+}
+
+/// Generates the TypeInfo for a path, e.g. 'Vec' with crate 'alloc' and module 'alloc::vec' for 'alloc::vec::Vec'
+/// Standard library types get the versions of the standard library, dependencies of this crate get their resolved versions, see 'dependency_impl::dependency_version'
+fn path_info(segments: &[&str], name: String, generics: Vec<TypeInfo>) -> TypeInfo {
+    let Some((krate, _)) = segments.split_first().filter(|_| segments.len() > 1) else {
+        if PRIMITIVES.contains(&name.as_str()) {
+            return primitive(&name, generics);
+        }
+        return TypeInfo::new(name, None, None, None, None, generics);
+    };
+    let mut module = segments[..segments.len() - 1].join("::");
+    let (crate_version, rustc_version) = if STD_CRATES.contains(krate) {
+        module = crate::std_impl::public_module(&module).to_owned();
+        (
+            crate::std_impl::std_version(),
+            crate::std_impl::std_version(),
+        )
+    } else {
+        match crate::dependency_impl::dependency_version(krate) {
+            Some(version) => (Some(version), Some(crate::std_impl::compiler_version())),
+            None => (None, None),
+        }
+    };
+    TypeInfo::new(
+        name,
+        Some((*krate).to_owned()),
+        Some(module),
+        crate_version,
+        rustc_version,
+        generics,
+    )
+}
+
+/// Parses Rust type syntax, see 'TypeInfo::from_rust_type_str'
+pub(crate) fn parse(input: &str, options: &ParseOptions) -> Result<TypeInfo, ParseError> {
+    if input.len() > options.max_length {
+        let offset = (0..=options.max_length)
+            .rev()
+            .find(|&i| input.is_char_boundary(i))
+            .unwrap_or(0);
+        return Err(ParseError::new(
+            ParseErrorKind::InputTooLong,
+            input,
+            offset,
+            &[],
+        ));
+    }
+    let mut parser = Parser {
+        input,
+        tokens: tokenize(input)?,
+        position: 0,
+        max_depth: options.max_depth,
+    };
+    let info = parser.parse_type(0)?;
+    if parser.peek().is_some() {
+        return Err(parser.error(
+            ParseErrorKind::RemainingToken(Box::new(info)),
+            &[ExpectedToken::End],
+        ));
+    }
+    Ok(info)
+}
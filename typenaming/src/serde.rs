@@ -0,0 +1,541 @@
+//! These modules allow to select the serde representation of a TypeInfo via '#[serde(with = "...")]'
+//! In human-readable formats, e.g. JSON, YAML or TOML, the deserializers of all modules accept both, the one-line string and the structured map
+//! Other formats, e.g. bincode or postcard, are not self-describing, there each module only accepts the form it writes
+//! Unknown entries of a structured map are kept as extra entries, like unknown keys of the one-line format
+
+use crate::TypeInfo;
+
+const TYPE_NAME: &str = "type_name";
+const CRATE: &str = "crate";
+const MODULE: &str = "module";
+const VERSION: &str = "version";
+const RUSTC: &str = "rustc";
+const GENERICS: &str = "generics";
+const EXTRA: &str = "extra";
+const FIELDS: &[&str] = &[TYPE_NAME, CRATE, MODULE, VERSION, RUSTC, GENERICS, EXTRA];
+
+/// Represents a TypeInfo as one-line string, as generated by 'TypeInfo::to_one_line_string'
+/// This is the same representation as used by the Serialize implementation of TypeInfo
+pub mod one_line {
+    use crate::TypeInfo;
+
+    /// Serializes a TypeInfo as one-line string
+    pub fn serialize<S>(info: &TypeInfo, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&info.to_one_line_string())
+    }
+
+    /// Deserializes a TypeInfo from either a one-line string or a structured map
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeInfo, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize(deserializer, super::Form::OneLine)
+    }
+}
+
+/// Represents a TypeInfo as map with the entries 'type_name', 'crate', 'module', 'version', 'rustc' and 'generics'
+/// Missing information is represented by null, generics are represented by a (possibly empty) array of maps
+/// Unknown entries of the one-line format are represented by the map 'extra', which is skipped if empty
+pub mod structured {
+    use crate::TypeInfo;
+
+    /// Serializes a TypeInfo as structured map
+    pub fn serialize<S>(info: &TypeInfo, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(
+            &super::Structured {
+                info,
+                compact: false,
+            },
+            serializer,
+        )
+    }
+
+    /// Deserializes a TypeInfo from either a one-line string or a structured map
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeInfo, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize(deserializer, super::Form::Structured)
+    }
+}
+
+/// Represents a TypeInfo as structured map, but skips missing information and empty generics
+/// Formats, which are not self-describing, e.g. bincode, cannot skip entries, there this is the same as 'structured'
+pub mod compact {
+    use crate::TypeInfo;
+
+    /// Serializes a TypeInfo as compact structured map
+    pub fn serialize<S>(info: &TypeInfo, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serde::Serialize::serialize(
+            &super::Structured {
+                info,
+                compact: true,
+            },
+            serializer,
+        )
+    }
+
+    /// Deserializes a TypeInfo from either a one-line string or a structured map
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TypeInfo, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize(deserializer, super::Form::Structured)
+    }
+}
+
+struct Structured<'a> {
+    info: &'a TypeInfo,
+    compact: bool,
+}
+
+impl<'a> serde::Serialize for Structured<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        // Formats, which are not self-describing, cannot skip entries
+        let human_readable = serializer.is_human_readable();
+        let compact = self.compact && human_readable;
+        let info = self.info;
+        let mut state = serializer.serialize_struct("TypeInfo", FIELDS.len())?;
+        state.serialize_field(TYPE_NAME, info.type_name())?;
+        let entries = [
+            (CRATE, info.crate_name().clone()),
+            (MODULE, info.crate_module().clone()),
+            (
+                VERSION,
+                info.crate_version().as_ref().map(|x| x.to_string()),
+            ),
+            (RUSTC, info.rustc_version().as_ref().map(|x| x.to_string())),
+        ];
+        for (key, value) in entries {
+            if compact && value.is_none() {
+                state.skip_field(key)?;
+            } else {
+                state.serialize_field(key, &value)?;
+            }
+        }
+        if compact && info.generics().is_empty() {
+            state.skip_field(GENERICS)?;
+        } else {
+            let generics = info
+                .generics()
+                .iter()
+                .map(|info| Structured { info, compact })
+                .collect::<Vec<_>>();
+            state.serialize_field(GENERICS, &generics)?;
+        }
+        if info.extra().is_empty() && human_readable {
+            state.skip_field(EXTRA)?;
+        } else {
+            state.serialize_field(EXTRA, info.extra())?;
+        }
+        state.end()
+    }
+}
+
+/// This selects the form, which is accepted by formats, which are not self-describing, i.e. the form written by the module
+#[derive(Clone, Copy)]
+enum Form {
+    OneLine,
+    Structured,
+}
+
+/// Human-readable formats, e.g. JSON, YAML or TOML, are self-describing, hence both forms are accepted
+/// Other formats, e.g. bincode or postcard, need a hint, so only the given form is accepted
+fn deserialize<'de, D>(deserializer: D, form: Form) -> Result<TypeInfo, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let AnyForm(info) = match form {
+        _ if deserializer.is_human_readable() => deserializer.deserialize_any(AnyFormVisitor)?,
+        Form::OneLine => deserializer.deserialize_str(AnyFormVisitor)?,
+        Form::Structured => deserializer.deserialize_struct("TypeInfo", FIELDS, AnyFormVisitor)?,
+    };
+    Ok(info)
+}
+
+/// Helper to deserialize a TypeInfo from either representation, which is also used for the generics
+struct AnyForm(TypeInfo);
+
+impl<'de> serde::Deserialize<'de> for AnyForm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserialize(deserializer, Form::Structured).map(AnyForm)
+    }
+}
+
+/// Unknown entries of a structured map are kept as extra entries, hence scalar values are converted to strings
+struct ExtraValue(String);
+
+impl<'de> serde::Deserialize<'de> for ExtraValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ExtraValueVisitor)
+    }
+}
+
+struct ExtraValueVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ExtraValueVisitor {
+    type Value = ExtraValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string, a number or a boolean")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E> {
+        Ok(ExtraValue(s.to_owned()))
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(ExtraValue(value.to_string()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(ExtraValue(value.to_string()))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(ExtraValue(value.to_string()))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(ExtraValue(value.to_string()))
+    }
+}
+
+struct AnyFormVisitor;
+
+impl<'de> serde::de::Visitor<'de> for AnyFormVisitor {
+    type Value = AnyForm;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a one-line TypeInfo string or a map with a 'type_name' entry")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        <TypeInfo as std::str::FromStr>::from_str(s)
+            .map(AnyForm)
+            .map_err(E::custom)
+    }
+
+    /// Formats, which are not self-describing, write the structured map as sequence of all entries
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        fn next<'de, T, A>(seq: &mut A, index: usize) -> Result<T, A::Error>
+        where
+            T: serde::Deserialize<'de>,
+            A: serde::de::SeqAccess<'de>,
+        {
+            use serde::de::Error;
+            seq.next_element()?
+                .ok_or_else(|| A::Error::invalid_length(index, &AnyFormVisitor))
+        }
+
+        let type_name: String = next(&mut seq, 0)?;
+        let crate_name: Option<String> = next(&mut seq, 1)?;
+        let crate_module: Option<String> = next(&mut seq, 2)?;
+        let crate_version: Option<String> = next(&mut seq, 3)?;
+        let rustc_version: Option<String> = next(&mut seq, 4)?;
+        let generics: Vec<AnyForm> = next(&mut seq, 5)?;
+        let extra: std::collections::BTreeMap<String, String> = next(&mut seq, 6)?;
+        Ok(AnyForm(
+            TypeInfo::new(
+                type_name,
+                crate_name,
+                crate_module,
+                parse_version(crate_version)?,
+                parse_version(rustc_version)?,
+                generics.into_iter().map(|AnyForm(info)| info).collect(),
+            )
+            .with_extra(extra),
+        ))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+        fn set<T, E: Error>(entry: &mut Option<T>, key: &'static str, value: T) -> Result<(), E> {
+            if entry.is_some() {
+                return Err(E::duplicate_field(key));
+            }
+            *entry = Some(value);
+            Ok(())
+        }
+
+        let mut type_name = None;
+        let mut crate_name = None;
+        let mut crate_module = None;
+        let mut crate_version = None;
+        let mut rustc_version = None;
+        let mut generics = None;
+        let mut extra = None;
+        // Unknown entries are kept, so that maps written by newer versions of this crate can be read
+        let mut unknown = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                TYPE_NAME => set(&mut type_name, TYPE_NAME, map.next_value::<String>()?)?,
+                CRATE => set(&mut crate_name, CRATE, map.next_value::<Option<String>>()?)?,
+                MODULE => set(
+                    &mut crate_module,
+                    MODULE,
+                    map.next_value::<Option<String>>()?,
+                )?,
+                VERSION => set(
+                    &mut crate_version,
+                    VERSION,
+                    map.next_value::<Option<String>>()?,
+                )?,
+                RUSTC => set(
+                    &mut rustc_version,
+                    RUSTC,
+                    map.next_value::<Option<String>>()?,
+                )?,
+                GENERICS => set(
+                    &mut generics,
+                    GENERICS,
+                    map.next_value::<Option<Vec<AnyForm>>>()?,
+                )?,
+                EXTRA => set(
+                    &mut extra,
+                    EXTRA,
+                    map.next_value::<Option<std::collections::BTreeMap<String, String>>>()?,
+                )?,
+                _ => {
+                    let ExtraValue(value) = map.next_value()?;
+                    unknown.push((key, value));
+                }
+            }
+        }
+        let mut extra = extra.flatten().unwrap_or_default();
+        for (key, value) in unknown {
+            if extra.insert(key, value).is_some() {
+                return Err(A::Error::custom(crate::ParseErrorKind::ExtraSetTwice));
+            }
+        }
+        Ok(AnyForm(
+            TypeInfo::new(
+                type_name.ok_or_else(|| A::Error::missing_field(TYPE_NAME))?,
+                crate_name.flatten(),
+                crate_module.flatten(),
+                parse_version(crate_version.flatten())?,
+                parse_version(rustc_version.flatten())?,
+                generics
+                    .flatten()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|AnyForm(info)| info)
+                    .collect(),
+            )
+            .with_extra(extra),
+        ))
+    }
+}
+
+fn parse_version<E: serde::de::Error>(
+    version: Option<String>,
+) -> Result<Option<crate::Version>, E> {
+    version
+        .map(|x| <crate::Version as std::str::FromStr>::from_str(&x))
+        .transpose()
+        .map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::TypeInfo;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Envelope {
+        #[serde(with = "crate::serde::one_line")]
+        one_line: TypeInfo,
+        #[serde(with = "crate::serde::structured")]
+        structured: TypeInfo,
+        #[serde(with = "crate::serde::compact")]
+        compact: TypeInfo,
+    }
+
+    fn example() -> TypeInfo {
+        TypeInfo::new(
+            "Gen".to_owned(),
+            Some("generic".to_owned()),
+            Some("test_module".to_owned()),
+            Some(semver::Version::new(1, 2, 3)),
+            None,
+            vec![TypeInfo::new(
+                "Test".to_owned(),
+                Some("testing".to_owned()),
+                None,
+                Some(semver::Version::new(0, 1, 0)),
+                Some(semver::Version::new(2, 0, 1)),
+                vec![],
+            )],
+        )
+    }
+
+    fn envelope() -> Envelope {
+        Envelope {
+            one_line: example(),
+            structured: example(),
+            compact: example(),
+        }
+    }
+
+    #[test]
+    fn structured_serialize() {
+        let serialized = serde_json::to_value(envelope()).unwrap();
+        assert_eq!(
+            serialized["structured"],
+            serde_json::json!({
+                "type_name": "Gen",
+                "crate": "generic",
+                "module": "test_module",
+                "version": "1.2.3",
+                "rustc": null,
+                "generics": [{
+                    "type_name": "Test",
+                    "crate": "testing",
+                    "module": null,
+                    "version": "0.1.0",
+                    "rustc": "2.0.1",
+                    "generics": []
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn compact_serialize() {
+        let serialized = serde_json::to_value(envelope()).unwrap();
+        assert_eq!(
+            serialized["compact"],
+            serde_json::json!({
+                "type_name": "Gen",
+                "crate": "generic",
+                "module": "test_module",
+                "version": "1.2.3",
+                "generics": [{
+                    "type_name": "Test",
+                    "crate": "testing",
+                    "version": "0.1.0",
+                    "rustc": "2.0.1"
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn one_line_serialize() {
+        let serialized = serde_json::to_value(envelope()).unwrap();
+        assert_eq!(
+            serialized["one_line"],
+            serde_json::json!(example().to_one_line_string())
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let serialized = serde_json::to_string(&envelope()).unwrap();
+        let deserialized: Envelope = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, envelope());
+    }
+
+    #[test]
+    fn deserialize_either_form() {
+        let serialized = serde_json::to_value(envelope()).unwrap();
+        let swapped = serde_json::json!({
+            "one_line": serialized["compact"],
+            "structured": serialized["one_line"],
+            "compact": serialized["structured"],
+        });
+        let deserialized: Envelope = serde_json::from_value(swapped).unwrap();
+        assert_eq!(deserialized, envelope());
+        let deserialized: TypeInfo =
+            serde_json::from_value(serialized["structured"].clone()).unwrap();
+        assert_eq!(deserialized, example());
+    }
+
+    #[test]
+    fn deserialize_unknown_field() {
+        let serialized = serde_json::json!({
+            "one_line": {"type_name": "Test", "unknown": 1, "extra": {"Size": "24"}},
+            "structured": {"type_name": "Test", "unknown": "a"},
+            "compact": {"type_name": "Test", "unknown": 1, "extra": {"unknown": "1"}},
+        });
+        let error = serde_json::from_value::<Envelope>(serialized.clone()).unwrap_err();
+        assert!(error.to_string().contains("duplicate entry"), "{error}");
+        let mut serialized = serialized;
+        serialized["compact"] = serialized["structured"].clone();
+        let deserialized: Envelope = serde_json::from_value(serialized).unwrap();
+        let extra = |entries: &[(&str, &str)]| -> std::collections::BTreeMap<String, String> {
+            entries
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            &extra(&[("Size", "24"), ("unknown", "1")]),
+            deserialized.one_line.extra()
+        );
+        assert_eq!(&extra(&[("unknown", "a")]), deserialized.structured.extra());
+    }
+
+    #[test]
+    fn not_self_describing() {
+        let extra = [("Size".to_owned(), "24".to_owned())].into_iter().collect();
+        let envelope = Envelope {
+            one_line: example(),
+            structured: example().with_extra(extra),
+            compact: example(),
+        };
+        let serialized = bincode::serialize(&envelope).unwrap();
+        let deserialized: Envelope = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, envelope);
+        let serialized = bincode::serialize(&example()).unwrap();
+        assert_eq!(
+            example(),
+            bincode::deserialize::<TypeInfo>(&serialized).unwrap()
+        );
+    }
+
+    #[test]
+    fn extra_entries() {
+        let extra = [("Size".to_owned(), "24".to_owned())].into_iter().collect();
+        let info = example().with_extra(extra);
+        let envelope = Envelope {
+            one_line: info.clone(),
+            structured: info.clone(),
+            compact: info.clone(),
+        };
+        let serialized = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(
+            serialized["compact"]["extra"],
+            serde_json::json!({"Size": "24"})
+        );
+        let deserialized: Envelope = serde_json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, envelope);
+    }
+}
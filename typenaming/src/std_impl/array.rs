@@ -0,0 +1,31 @@
+use crate::{TypeInfo, TypeNameable};
+
+/// Arrays are described by their element type and their length, which is stored as const argument
+fn info(element: TypeInfo, length: usize) -> TypeInfo {
+    TypeInfo::new(
+        "array".to_owned(),
+        Some("core".to_owned()),
+        Some("core::primitive".to_owned()),
+        super::std_version(),
+        super::std_version(),
+        vec![element, TypeInfo::new_const_argument(length.to_string())],
+    )
+}
+
+impl<T, const N: usize> TypeNameable for [T; N]
+where
+    T: TypeNameable,
+{
+    fn type_info() -> TypeInfo {
+        info(T::type_info(), N)
+    }
+}
+
+impl<T, const N: usize> TypeNameable for &[T; N]
+where
+    T: TypeNameable,
+{
+    fn type_info() -> TypeInfo {
+        info(T::type_info(), N)
+    }
+}
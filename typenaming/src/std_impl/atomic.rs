@@ -0,0 +1,50 @@
+use crate::{implementing, implementing_generic};
+
+implementing!(
+    core::sync::atomic,
+    AtomicBool = core::sync::atomic::AtomicBool
+);
+
+implementing!(core::sync::atomic, AtomicU8 = core::sync::atomic::AtomicU8);
+implementing!(
+    core::sync::atomic,
+    AtomicU16 = core::sync::atomic::AtomicU16
+);
+implementing!(
+    core::sync::atomic,
+    AtomicU32 = core::sync::atomic::AtomicU32
+);
+implementing!(
+    core::sync::atomic,
+    AtomicU64 = core::sync::atomic::AtomicU64
+);
+implementing!(
+    core::sync::atomic,
+    AtomicUsize = core::sync::atomic::AtomicUsize
+);
+
+implementing!(core::sync::atomic, AtomicI8 = core::sync::atomic::AtomicI8);
+implementing!(
+    core::sync::atomic,
+    AtomicI16 = core::sync::atomic::AtomicI16
+);
+implementing!(
+    core::sync::atomic,
+    AtomicI32 = core::sync::atomic::AtomicI32
+);
+implementing!(
+    core::sync::atomic,
+    AtomicI64 = core::sync::atomic::AtomicI64
+);
+implementing!(
+    core::sync::atomic,
+    AtomicIsize = core::sync::atomic::AtomicIsize
+);
+
+implementing_generic!(
+    core::sync::atomic,
+    AtomicPtr = core::sync::atomic::AtomicPtr,
+    T
+);
+
+implementing!(core::sync::atomic, Ordering = core::sync::atomic::Ordering);
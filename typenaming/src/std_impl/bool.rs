@@ -1,3 +1,3 @@
 use crate::implementing;
 
-implementing!(bool);
+implementing!(core::primitive, bool);
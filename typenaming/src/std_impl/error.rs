@@ -0,0 +1,12 @@
+use crate::implementing;
+
+implementing!(std::io, Error = std::io::Error);
+
+implementing!(core::num, ParseIntError = core::num::ParseIntError);
+implementing!(core::num, TryFromIntError = core::num::TryFromIntError);
+implementing!(core::num, ParseFloatError = core::num::ParseFloatError);
+implementing!(core::str, ParseBoolError = core::str::ParseBoolError);
+implementing!(core::str, Utf8Error = core::str::Utf8Error);
+implementing!(core::char, ParseCharError = core::char::ParseCharError);
+implementing!(core::net, AddrParseError = core::net::AddrParseError);
+implementing!(alloc::string, FromUtf8Error = std::string::FromUtf8Error);
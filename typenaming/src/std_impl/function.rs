@@ -0,0 +1,43 @@
+use crate::{implementing_fn, TypeInfo, TypeNameable};
+
+/// Function pointers are described by their kind ('fn', 'unsafe fn', 'extern "C" fn' or 'unsafe extern "C" fn')
+/// The generics are the argument types, followed by the return type
+#[doc(hidden)]
+pub fn info(name: &'static str, mut inputs: Vec<TypeInfo>, output: TypeInfo) -> TypeInfo {
+    inputs.push(output);
+    TypeInfo::new(
+        name.to_owned(),
+        Some("core".to_owned()),
+        Some("core::primitive".to_owned()),
+        super::std_version(),
+        super::std_version(),
+        inputs,
+    )
+}
+
+impl TypeNameable for () {
+    fn type_info() -> TypeInfo {
+        TypeInfo::new(
+            "()".to_owned(),
+            Some("core".to_owned()),
+            Some("core::primitive".to_owned()),
+            super::std_version(),
+            super::std_version(),
+            vec![],
+        )
+    }
+}
+
+implementing_fn!();
+implementing_fn!(A1);
+implementing_fn!(A1, A2);
+implementing_fn!(A1, A2, A3);
+implementing_fn!(A1, A2, A3, A4);
+implementing_fn!(A1, A2, A3, A4, A5);
+implementing_fn!(A1, A2, A3, A4, A5, A6);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7, A8);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11);
+implementing_fn!(A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12);
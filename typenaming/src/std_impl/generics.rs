@@ -1,7 +1,20 @@
-use crate::implementing_generic;
+use crate::{implementing_generic, implementing_pointer};
 
-//pub use std::sync::Arc;
+implementing_generic!(alloc::vec, Vec, T);
+implementing_generic!(core::result, Result, T, E);
 
-implementing_generic!(Vec, T);
-//implementing_generic!(Arc, T);
-implementing_generic!(Result, T, E);
+implementing_pointer!(alloc::boxed, Box = std::boxed::Box);
+implementing_pointer!(alloc::rc, Rc = std::rc::Rc);
+implementing_pointer!(alloc::sync, Arc = std::sync::Arc);
+
+implementing_generic!(core::option, Option, T);
+implementing_generic!(alloc::collections, VecDeque = std::collections::VecDeque, T);
+implementing_generic!(alloc::collections, BTreeSet = std::collections::BTreeSet, T);
+implementing_generic!(
+    alloc::collections,
+    BTreeMap = std::collections::BTreeMap,
+    K,
+    V
+);
+implementing_generic!(std::collections, HashSet = std::collections::HashSet, T);
+implementing_generic!(std::collections, HashMap = std::collections::HashMap, K, V);
@@ -1,22 +1,27 @@
 /// This is an unstable helper macro, which is used to implement TypeNameable for several types
+/// The first argument is the public path of the module, which exports the type, e.g. 'alloc::string'
+/// If the type is not in scope, a public path to it has to be given, e.g. 'Duration = core::time::Duration'
 #[doc(hidden)]
 #[macro_export]
 macro_rules! implementing {
-    ( $t:ident ) => {
+    ( $krate:ident $(:: $module:ident)*, $t:ident ) => {
+        $crate::implementing!($krate $(:: $module)*, $t = $t);
+    };
+    ( $krate:ident $(:: $module:ident)*, $t:ident = $($p:ident)::+ ) => {
         #[allow(non_snake_case)]
         #[doc(hidden)]
         pub mod $t {
-            use crate::{TypeInfo, TypeNameable};
+            use $crate::{TypeInfo, TypeNameable};
 
-            type T = $t;
+            type T = $($p)::+;
             const TT: &'static str = stringify!($t);
             fn info() -> TypeInfo {
                 TypeInfo::new(
                     TT.to_owned(),
-                    Some("core".to_owned()),
-                    Some("core".to_owned()),
-                    Some(crate::Version::new(1, 0, 0)),
-                    Some(crate::Version::new(1, 0, 0)),
+                    Some(stringify!($krate).to_owned()),
+                    Some(concat!(stringify!($krate) $(, "::", stringify!($module))*).to_owned()),
+                    $crate::std_impl::std_version(),
+                    $crate::std_impl::std_version(),
                     vec![],
                 )
             }
@@ -37,28 +42,40 @@ macro_rules! implementing {
 }
 
 /// This is an unstable helper macro, which is used to implement TypeNameable for several types
+/// The first argument is the public path of the module, which exports the type, e.g. 'core::primitive'
+/// If the type is not in scope, a public path to it has to be given, e.g. 'Path = std::path::Path'
+/// It is used for unsized types, e.g. 'str', so both the type and references to it, e.g. '&str' or 'Box<str>', are covered
 #[doc(hidden)]
 #[macro_export]
 macro_rules! implementing_unsized {
-    ( $t:ident ) => {
+    ( $krate:ident $(:: $module:ident)*, $t:ident ) => {
+        $crate::implementing_unsized!($krate $(:: $module)*, $t = $t);
+    };
+    ( $krate:ident $(:: $module:ident)*, $t:ident = $($p:ident)::+ ) => {
         #[allow(non_snake_case)]
         #[doc(hidden)]
         pub mod $t {
-            use crate::{TypeInfo, TypeNameable};
+            use $crate::{TypeInfo, TypeNameable};
 
-            type T = $t;
+            type T = $($p)::+;
             const TT: &'static str = stringify!($t);
             fn info() -> TypeInfo {
                 TypeInfo::new(
                     TT.to_owned(),
-                    Some("core".to_owned()),
-                    Some("core".to_owned()),
-                    Some(crate::Version::new(1, 0, 0)),
-                    Some(crate::Version::new(1, 0, 0)),
+                    Some(stringify!($krate).to_owned()),
+                    Some(concat!(stringify!($krate) $(, "::", stringify!($module))*).to_owned()),
+                    $crate::std_impl::std_version(),
+                    $crate::std_impl::std_version(),
                     vec![],
                 )
             }
 
+            impl TypeNameable for T {
+                fn type_info() -> TypeInfo {
+                    info()
+                }
+            }
+
             impl<'a> TypeNameable for &'a T {
                 fn type_info() -> TypeInfo {
                     info()
@@ -69,28 +86,33 @@ macro_rules! implementing_unsized {
 }
 
 /// This is an unstable helper macro, which is used to implement TypeNameable for several types
+/// The first argument is the public path of the module, which exports the type, e.g. 'alloc::vec'
+/// If the type is not in scope, a public path to it has to be given, e.g. 'Wrapping = core::num::Wrapping'
 #[doc(hidden)]
 #[macro_export]
 macro_rules! implementing_generic {
-    ( $t:ident, $($g:ident),* ) => {
+    ( $krate:ident $(:: $module:ident)*, $t:ident, $($g:ident),* ) => {
+        $crate::implementing_generic!($krate $(:: $module)*, $t = $t, $($g),*);
+    };
+    ( $krate:ident $(:: $module:ident)*, $t:ident = $($p:ident)::+, $($g:ident),* ) => {
         #[doc(hidden)]
         #[allow(non_snake_case)]
         pub mod $t {
-            use crate::{TypeNameable, TypeInfo};
+            use $crate::{TypeNameable, TypeInfo};
 
             const TT: &'static str = stringify!($t);
             fn info($($g: TypeInfo,)*) -> TypeInfo {
                 TypeInfo::new(
                     TT.to_owned(),
-                    Some("core".to_owned()),
-                    Some("core".to_owned()),
-                    Some(crate::Version::new(1, 0, 0)),
-                    Some(crate::Version::new(1, 0, 0)),
+                    Some(stringify!($krate).to_owned()),
+                    Some(concat!(stringify!($krate) $(, "::", stringify!($module))*).to_owned()),
+                    $crate::std_impl::std_version(),
+                    $crate::std_impl::std_version(),
                     vec![$($g,)*],
                 )
             }
 
-            impl<$($g,)*> TypeNameable for $t<$($g,)*>
+            impl<$($g,)*> TypeNameable for $($p)::+<$($g,)*>
             where
                 $($g: TypeNameable,)*
             {
@@ -99,7 +121,7 @@ macro_rules! implementing_generic {
                 }
             }
 
-            impl<'a, $($g,)*> TypeNameable for &'a $t<$($g,)*>
+            impl<'a, $($g,)*> TypeNameable for &'a $($p)::+<$($g,)*>
             where
                 $($g: TypeNameable,)*
             {
@@ -110,3 +132,117 @@ macro_rules! implementing_generic {
         }
     };
 }
+
+/// This is an unstable helper macro, which is used to implement TypeNameable for several types
+/// It is used for pointer types with a single, possibly unsized, type parameter, e.g. 'Box<dyn Trait>'
+/// The first argument is the public path of the module, which exports the type, e.g. 'alloc::boxed'
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implementing_pointer {
+    ( $krate:ident $(:: $module:ident)*, $t:ident = $($p:ident)::+ ) => {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        pub mod $t {
+            use $crate::{TypeNameable, TypeInfo};
+
+            const TT: &'static str = stringify!($t);
+            fn info(T: TypeInfo) -> TypeInfo {
+                TypeInfo::new(
+                    TT.to_owned(),
+                    Some(stringify!($krate).to_owned()),
+                    Some(concat!(stringify!($krate) $(, "::", stringify!($module))*).to_owned()),
+                    $crate::std_impl::std_version(),
+                    $crate::std_impl::std_version(),
+                    vec![T],
+                )
+            }
+
+            impl<T> TypeNameable for $($p)::+<T>
+            where
+                T: TypeNameable + ?Sized,
+            {
+                fn type_info() -> TypeInfo {
+                    info(T::type_info())
+                }
+            }
+
+            impl<'a, T> TypeNameable for &'a $($p)::+<T>
+            where
+                T: TypeNameable + ?Sized,
+            {
+                fn type_info() -> TypeInfo {
+                    info(T::type_info())
+                }
+            }
+        }
+    };
+}
+
+/// This is an unstable helper macro, which is used to implement TypeNameable for several types
+/// It is used for specific instances of generic types, e.g. 'NonZeroU8 = core::num::NonZeroU8, NonZero<u8>'
+/// The first argument is the public path of the module, which exports the type, e.g. 'core::num'
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implementing_instance {
+    ( $krate:ident $(:: $module:ident)*, $alias:ident = $($p:ident)::+, $t:ident<$($g:ty),*> ) => {
+        #[allow(non_snake_case)]
+        #[doc(hidden)]
+        pub mod $alias {
+            use $crate::{TypeInfo, TypeNameable};
+
+            type T = $($p)::+;
+            const TT: &'static str = stringify!($t);
+            fn info() -> TypeInfo {
+                TypeInfo::new(
+                    TT.to_owned(),
+                    Some(stringify!($krate).to_owned()),
+                    Some(concat!(stringify!($krate) $(, "::", stringify!($module))*).to_owned()),
+                    $crate::std_impl::std_version(),
+                    $crate::std_impl::std_version(),
+                    vec![$(<$g as TypeNameable>::type_info(),)*],
+                )
+            }
+
+            impl TypeNameable for T {
+                fn type_info() -> TypeInfo {
+                    info()
+                }
+            }
+
+            impl<'a> TypeNameable for &'a T {
+                fn type_info() -> TypeInfo {
+                    info()
+                }
+            }
+        }
+    };
+}
+
+/// This is an unstable helper macro, which is used to implement TypeNameable for function pointers
+/// The arguments are the names of the generic argument types, the return type is always called 'R'
+/// Higher-ranked function pointers, e.g. `fn(&str)`, are not covered, since their lifetime cannot be a generic parameter of the implementation
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implementing_fn {
+    ( $($a:ident),* ) => {
+        $crate::implementing_fn!(@ "fn", fn($($a),*) -> R, $($a),*);
+        $crate::implementing_fn!(@ "unsafe fn", unsafe fn($($a),*) -> R, $($a),*);
+        $crate::implementing_fn!(@ "extern \"C\" fn", extern "C" fn($($a),*) -> R, $($a),*);
+        $crate::implementing_fn!(@ "unsafe extern \"C\" fn", unsafe extern "C" fn($($a),*) -> R, $($a),*);
+    };
+    ( @ $name:literal, $f:ty, $($a:ident),* ) => {
+        impl<$($a,)* R> $crate::TypeNameable for $f
+        where
+            $($a: $crate::TypeNameable,)*
+            R: $crate::TypeNameable,
+        {
+            fn type_info() -> $crate::TypeInfo {
+                $crate::std_impl::function::info(
+                    $name,
+                    vec![$(<$a as $crate::TypeNameable>::type_info(),)*],
+                    <R as $crate::TypeNameable>::type_info(),
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,9 @@
+use crate::{implementing, implementing_generic};
+
+implementing!(core::cmp, Ordering = core::cmp::Ordering);
+implementing_generic!(core::marker, PhantomData = core::marker::PhantomData, T);
+implementing!(core::any, TypeId = core::any::TypeId);
+implementing!(
+    std::hash,
+    RandomState = std::collections::hash_map::RandomState
+);
@@ -1,9 +1,81 @@
+/// This module implements TypeName for arrays
+pub mod array;
+/// This module implements TypeName for atomic types
+pub mod atomic;
 /// This module implements TypeName for bool
 pub mod bool;
+/// This module implements TypeName for error types
+pub mod error;
+/// This module implements TypeName for function pointers and the unit type
+pub mod function;
 /// This module implements TypeName for some generic types
 pub mod generics;
 mod impl_macro;
+/// This module implements TypeName for some types from 'core::cmp', 'core::marker', 'core::any' and 'std::hash'
+pub mod misc;
+/// This module implements TypeName for some network address types
+pub mod net;
 /// This module implements TypeName for some numeric types
 pub mod numerics;
+/// This module implements TypeName for range types
+pub mod ops;
+/// This module implements TypeName for path types
+pub mod path;
 /// This module implements TypeName for some string-like types
 pub mod string;
+/// This module implements TypeName for time types
+pub mod time;
+
+/// This is the version of the compiler, which was used to build this crate.
+#[doc(hidden)]
+pub fn compiler_version() -> crate::Version {
+    <crate::Version as std::str::FromStr>::from_str(env!("TYPENAMING_RUSTC_VERSION"))
+        .expect("Failed to parse rustc version recorded by build script")
+}
+
+/// This is used as crate version and as rustc version of types from 'core', 'alloc' and 'std'.
+/// If the feature 'std-versions' is disabled, None is returned, so that these types compare equal across toolchains.
+#[doc(hidden)]
+pub fn std_version() -> Option<crate::Version> {
+    if cfg!(feature = "std-versions") {
+        Some(compiler_version())
+    } else {
+        None
+    }
+}
+
+/// Types of the standard library are described by the public module, which re-exports them, e.g. 'core::num' for 'ParseIntError'
+/// 'std::any::type_name' shows the private module, which defines them, e.g. 'core::num::error', these are mapped by this table
+const PUBLIC_MODULES: &[(&str, &str)] = &[
+    ("alloc::collections::binary_heap", "alloc::collections"),
+    ("alloc::collections::btree::map", "alloc::collections"),
+    ("alloc::collections::btree::set", "alloc::collections"),
+    ("alloc::collections::linked_list", "alloc::collections"),
+    ("alloc::collections::vec_deque", "alloc::collections"),
+    ("alloc::ffi::c_str", "alloc::ffi"),
+    ("core::char::convert", "core::char"),
+    ("core::ffi::c_str", "core::ffi"),
+    ("core::net::ip_addr", "core::net"),
+    ("core::net::parser", "core::net"),
+    ("core::net::socket_addr", "core::net"),
+    ("core::num::dec2flt", "core::num"),
+    ("core::num::error", "core::num"),
+    ("core::num::nonzero", "core::num"),
+    ("core::num::saturating", "core::num"),
+    ("core::num::wrapping", "core::num"),
+    ("core::ops::range", "core::ops"),
+    ("core::str::error", "core::str"),
+    ("std::collections::hash::map", "std::collections"),
+    ("std::collections::hash::set", "std::collections"),
+    ("std::ffi::os_str", "std::ffi"),
+    ("std::hash::random", "std::hash"),
+    ("std::io::error", "std::io"),
+];
+
+/// Maps a private module of the standard library to its public module, other modules are returned unchanged
+pub(crate) fn public_module(module: &str) -> &str {
+    PUBLIC_MODULES
+        .iter()
+        .find(|(private, _)| *private == module)
+        .map_or(module, |(_, public)| public)
+}
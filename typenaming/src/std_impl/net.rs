@@ -0,0 +1,8 @@
+use crate::implementing;
+
+implementing!(core::net, IpAddr = core::net::IpAddr);
+implementing!(core::net, Ipv4Addr = core::net::Ipv4Addr);
+implementing!(core::net, Ipv6Addr = core::net::Ipv6Addr);
+implementing!(core::net, SocketAddr = core::net::SocketAddr);
+implementing!(core::net, SocketAddrV4 = core::net::SocketAddrV4);
+implementing!(core::net, SocketAddrV6 = core::net::SocketAddrV6);
@@ -1,18 +1,51 @@
-use crate::implementing;
+use crate::{implementing, implementing_generic, implementing_instance};
 
-implementing!(u8);
-implementing!(u16);
-implementing!(u32);
-implementing!(u64);
-implementing!(u128);
-implementing!(usize);
+implementing!(core::primitive, u8);
+implementing!(core::primitive, u16);
+implementing!(core::primitive, u32);
+implementing!(core::primitive, u64);
+implementing!(core::primitive, u128);
+implementing!(core::primitive, usize);
 
-implementing!(i8);
-implementing!(i16);
-implementing!(i32);
-implementing!(i64);
-implementing!(i128);
-implementing!(isize);
+implementing!(core::primitive, i8);
+implementing!(core::primitive, i16);
+implementing!(core::primitive, i32);
+implementing!(core::primitive, i64);
+implementing!(core::primitive, i128);
+implementing!(core::primitive, isize);
 
-implementing!(f32);
-implementing!(f64);
+implementing!(core::primitive, f32);
+implementing!(core::primitive, f64);
+
+implementing_instance!(core::num, NonZeroU8 = core::num::NonZeroU8, NonZero<u8>);
+implementing_instance!(core::num, NonZeroU16 = core::num::NonZeroU16, NonZero<u16>);
+implementing_instance!(core::num, NonZeroU32 = core::num::NonZeroU32, NonZero<u32>);
+implementing_instance!(core::num, NonZeroU64 = core::num::NonZeroU64, NonZero<u64>);
+implementing_instance!(
+    core::num,
+    NonZeroU128 = core::num::NonZeroU128,
+    NonZero<u128>
+);
+implementing_instance!(
+    core::num,
+    NonZeroUsize = core::num::NonZeroUsize,
+    NonZero<usize>
+);
+
+implementing_instance!(core::num, NonZeroI8 = core::num::NonZeroI8, NonZero<i8>);
+implementing_instance!(core::num, NonZeroI16 = core::num::NonZeroI16, NonZero<i16>);
+implementing_instance!(core::num, NonZeroI32 = core::num::NonZeroI32, NonZero<i32>);
+implementing_instance!(core::num, NonZeroI64 = core::num::NonZeroI64, NonZero<i64>);
+implementing_instance!(
+    core::num,
+    NonZeroI128 = core::num::NonZeroI128,
+    NonZero<i128>
+);
+implementing_instance!(
+    core::num,
+    NonZeroIsize = core::num::NonZeroIsize,
+    NonZero<isize>
+);
+
+implementing_generic!(core::num, Wrapping = core::num::Wrapping, T);
+implementing_generic!(core::num, Saturating = core::num::Saturating, T);
@@ -0,0 +1,12 @@
+use crate::{implementing, implementing_generic};
+
+implementing_generic!(core::ops, Range = core::ops::Range, Idx);
+implementing_generic!(core::ops, RangeInclusive = core::ops::RangeInclusive, Idx);
+implementing_generic!(core::ops, RangeFrom = core::ops::RangeFrom, Idx);
+implementing_generic!(core::ops, RangeTo = core::ops::RangeTo, Idx);
+implementing_generic!(
+    core::ops,
+    RangeToInclusive = core::ops::RangeToInclusive,
+    Idx
+);
+implementing!(core::ops, RangeFull = core::ops::RangeFull);
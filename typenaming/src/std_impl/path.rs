@@ -0,0 +1,4 @@
+use crate::{implementing, implementing_unsized};
+
+implementing_unsized!(std::path, Path = std::path::Path);
+implementing!(std::path, PathBuf = std::path::PathBuf);
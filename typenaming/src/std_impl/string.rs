@@ -1,5 +1,10 @@
 use crate::{implementing, implementing_unsized};
 
-implementing!(char);
-implementing!(String);
-implementing_unsized!(str);
+implementing!(core::primitive, char);
+implementing!(alloc::string, String);
+implementing_unsized!(core::primitive, str);
+
+implementing_unsized!(std::ffi, OsStr = std::ffi::OsStr);
+implementing!(std::ffi, OsString = std::ffi::OsString);
+implementing_unsized!(core::ffi, CStr = core::ffi::CStr);
+implementing!(alloc::ffi, CString = std::ffi::CString);
@@ -0,0 +1,5 @@
+use crate::implementing;
+
+implementing!(core::time, Duration = core::time::Duration);
+implementing!(std::time, Instant = std::time::Instant);
+implementing!(std::time, SystemTime = std::time::SystemTime);
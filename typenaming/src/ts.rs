@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::type_shape::{collect_shapes, declaration_name, declaration_names};
+use crate::{Fields, TypeInfo, TypeNameable, TypeShape};
+
+/// Generates TypeScript declarations for the given type and for all types, which are referenced by its fields
+/// Field and variant metadata is recorded by deriving TypeNameable with '#[typenameable(fields)]', types without it are mapped structurally, e.g. `Vec<T>` to `Array<T>`, or to 'unknown'
+/// The declarations describe serde's default JSON representation, i.e. enums are externally tagged, serde attributes like 'rename' or 'tag' are not taken into account
+/// Declarations are named like the type, e.g. 'Page_User' for `Page<User>`, if two types share a name, both are prefixed by their module, e.g. 'api_Error'
+pub fn declarations<T: TypeNameable + ?Sized>() -> String {
+    let registry = Registry::collect(T::type_info(), T::type_shape());
+    let mut out = String::new();
+    if registry.shapes.is_empty() {
+        let info = T::type_info();
+        writeln!(
+            out,
+            "export type {} = {};",
+            declaration_name(&info),
+            registry.ts_type(&info)
+        )
+        .expect("Writing to a String cannot fail");
+    }
+    for (i, (info, shape)) in registry.shapes.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        registry.write_declaration(info, shape, &mut out);
+    }
+    out
+}
+
+/// Writes the TypeScript declarations of the given type to a '.d.ts' file, see 'declarations'
+pub fn export<T: TypeNameable + ?Sized>(path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+    std::fs::write(path, declarations::<T>())
+}
+
+/// This collects all types with shape in the order of their first appearance, together with their unique declaration names
+struct Registry {
+    shapes: Vec<(TypeInfo, TypeShape)>,
+    names: HashMap<TypeInfo, String>,
+}
+
+impl Registry {
+    fn collect(info: TypeInfo, shape: Option<TypeShape>) -> Self {
+        let shapes = collect_shapes(info, shape);
+        let names = declaration_names(&shapes);
+        Registry { shapes, names }
+    }
+
+    fn write_declaration(&self, info: &TypeInfo, shape: &TypeShape, out: &mut String) {
+        let name = &self.names[info];
+        match shape {
+            TypeShape::Struct(Fields::Named(fields)) => {
+                writeln!(out, "export interface {name} {{")
+                    .expect("Writing to a String cannot fail");
+                for field in fields {
+                    writeln!(
+                        out,
+                        "    {}: {};",
+                        field.name().unwrap_or_default(),
+                        self.ts_type(field.type_info())
+                    )
+                    .expect("Writing to a String cannot fail");
+                }
+                out.push_str("}\n");
+            }
+            TypeShape::Struct(fields) => {
+                writeln!(out, "export type {name} = {};", self.fields_type(fields))
+                    .expect("Writing to a String cannot fail");
+            }
+            TypeShape::Enum(variants) => {
+                let variants: Vec<String> = variants
+                    .iter()
+                    .map(|variant| match variant.fields() {
+                        Fields::Unit => format!("\"{}\"", variant.name()),
+                        fields => format!("{{ {}: {} }}", variant.name(), self.fields_type(fields)),
+                    })
+                    .collect();
+                let variants = if variants.is_empty() {
+                    "never".to_owned()
+                } else {
+                    variants.join(" | ")
+                };
+                writeln!(out, "export type {name} = {variants};")
+                    .expect("Writing to a String cannot fail");
+            }
+        }
+    }
+
+    /// Maps fields to an inline type, e.g. a single unnamed field to its type
+    fn fields_type(&self, fields: &Fields) -> String {
+        match fields {
+            Fields::Unit => "null".to_owned(),
+            Fields::Named(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|field| {
+                        format!(
+                            "{}: {}",
+                            field.name().unwrap_or_default(),
+                            self.ts_type(field.type_info())
+                        )
+                    })
+                    .collect();
+                format!("{{ {} }}", fields.join("; "))
+            }
+            Fields::Unnamed(fields) if fields.len() == 1 => self.ts_type(fields[0].type_info()),
+            Fields::Unnamed(fields) => {
+                let fields: Vec<String> = fields
+                    .iter()
+                    .map(|field| self.ts_type(field.type_info()))
+                    .collect();
+                format!("[{}]", fields.join(", "))
+            }
+        }
+    }
+
+    /// Maps a type to TypeScript, types with shape are referenced by their declaration name
+    fn ts_type(&self, info: &TypeInfo) -> String {
+        if let Some(name) = self.names.get(info) {
+            return name.clone();
+        }
+        let generic = |i: usize| match info.generics().get(i) {
+            Some(generic) => self.ts_type(generic),
+            None => "unknown".to_owned(),
+        };
+        let is_std = matches!(info.crate_name().as_deref(), Some("core" | "alloc" | "std"));
+        match info.type_name() {
+            _ if !is_std => "unknown".to_owned(),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+            | "i128" | "isize" | "f32" | "f64" => "number".to_owned(),
+            "bool" => "boolean".to_owned(),
+            "char" | "str" | "String" => "string".to_owned(),
+            "()" | "PhantomData" => "null".to_owned(),
+            "!" => "never".to_owned(),
+            "tuple" => {
+                let elements: Vec<String> = (0..info.generics().len()).map(generic).collect();
+                format!("[{}]", elements.join(", "))
+            }
+            "array" | "slice" | "Vec" | "VecDeque" | "LinkedList" | "BTreeSet" | "HashSet"
+            | "BinaryHeap" => format!("Array<{}>", generic(0)),
+            "BTreeMap" | "HashMap" => format!("Record<{}, {}>", generic(0), generic(1)),
+            "Option" => format!("{} | null", generic(0)),
+            "Box" | "Rc" | "Arc" | "Cow" | "Cell" | "RefCell" | "Mutex" | "RwLock" => generic(0),
+            _ => "unknown".to_owned(),
+        }
+    }
+}
@@ -1,7 +1,10 @@
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
+use crate::{DecodeError, TypeNameable};
+
 /// This type represents some basic information about a given type
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, TypeNameable)]
 pub struct TypeInfo {
     type_name: String,
     crate_name: Option<String>,
@@ -9,15 +12,7 @@ pub struct TypeInfo {
     crate_version: Option<::semver::Version>,
     rustc_version: Option<::semver::Version>,
     generics: Vec<TypeInfo>,
-}
-
-#[derive(Debug)]
-enum Token<'a> {
-    String(&'a str),
-    GenericStart,
-    GenericNext,
-    GenericEnd,
-    Comma,
+    extra: BTreeMap<String, String>,
 }
 
 impl TypeInfo {
@@ -46,6 +41,64 @@ impl TypeInfo {
     pub fn generics(&self) -> &[TypeInfo] {
         &self.generics
     }
+    /// Check whether the type is a function pointer, e.g. 'fn(u8) -> bool' or 'unsafe extern "C" fn()'
+    /// Function pointers are stored with the argument types as generics, followed by the return type
+    pub fn is_function_pointer(&self) -> bool {
+        const KINDS: [&str; 4] = [
+            "fn",
+            "unsafe fn",
+            "extern \"C\" fn",
+            "unsafe extern \"C\" fn",
+        ];
+        KINDS.contains(&self.type_name.as_str())
+            && self.crate_name.as_deref() == Some("core")
+            && self.crate_module.as_deref() == Some("core::primitive")
+            && !self.generics.is_empty()
+    }
+    /// Get list of argument types - if the type is a function pointer
+    pub fn function_inputs(&self) -> Option<&[TypeInfo]> {
+        if self.is_function_pointer() {
+            self.generics.split_last().map(|(_, inputs)| inputs)
+        } else {
+            None
+        }
+    }
+    /// Get return type - if the type is a function pointer
+    /// Note that a missing return type is represented by '()'
+    pub fn function_output(&self) -> Option<&TypeInfo> {
+        if self.is_function_pointer() {
+            self.generics.last()
+        } else {
+            None
+        }
+    }
+    /// Check whether this is a const generic argument, e.g. the '3' in '[u8; 3]'
+    /// Const arguments are stored as generics, which carry the value as type name and no further information
+    pub fn is_const_argument(&self) -> bool {
+        let value = self.type_name.as_str();
+        let is_literal = value.parse::<i128>().is_ok()
+            || value.parse::<u128>().is_ok()
+            || value == "true"
+            || value == "false"
+            || (value.len() > 2 && value.starts_with('\'') && value.ends_with('\''));
+        is_literal
+            && self.crate_name.is_none()
+            && self.crate_module.is_none()
+            && self.crate_version.is_none()
+            && self.rustc_version.is_none()
+            && self.generics.is_empty()
+            && self.extra.is_empty()
+    }
+    /// Get entries of the one-line format, which are not known to this version of typenaming
+    /// These are kept by lenient parsing and are written again by 'to_one_line_string'
+    pub fn extra(&self) -> &BTreeMap<String, String> {
+        &self.extra
+    }
+    /// Replaces the unknown entries, see 'extra'
+    pub fn with_extra(mut self, extra: BTreeMap<String, String>) -> Self {
+        self.extra = extra;
+        self
+    }
     /// Constructor
     pub const fn new(
         type_name: String,
@@ -62,229 +115,126 @@ impl TypeInfo {
             crate_version,
             rustc_version,
             generics,
+            extra: BTreeMap::new(),
         }
     }
+    /// Constructor for a const generic argument, e.g. the '3' in '[u8; 3]'
+    /// The value is expected to be an integer, a bool or a char literal
+    pub const fn new_const_argument(value: String) -> Self {
+        Self::new(value, None, None, None, None, vec![])
+    }
+    /// Parses Rust type syntax, e.g. the output of 'std::any::type_name', like `alloc::vec::Vec<core::option::Option<u8>>`
+    /// Crate and module are derived from the path, references are skipped and trait objects are named like 'dyn Trait + Send'
+    /// Versions are only known for the standard library and for the optional dependencies of this crate
+    pub fn from_rust_type_str(s: &str) -> Result<Self, ParseError> {
+        crate::rust_type::parse(s, &ParseOptions::default())
+    }
+    /// Version of the one-line format, which is generated by 'to_one_line_string'
+    pub const ONE_LINE_FORMAT_VERSION: u32 = 1;
     /// Generates a one-line version, in the form "{type_name}<{generics}>, Crate={crate_name}, Module={crate_module}, Version={crate_version}, Rustc={rustc_version}"
     /// Note that None-entries are skipped
     /// If there are no generics, the '<…>'-part is skipped
     /// If there are generics, the generics are recursively joined with ';', using this method
+    /// The characters '\', ',', ';', '<', '>', '=' and '@' are escaped by a backslash, an empty text is written as '\e'
+    /// Control characters and whitespace at the start or end of a text are written as '\u{…}'
+    /// Parsing the result via 'FromStr' yields the original TypeInfo
     pub fn to_one_line_string(&self) -> String {
-        let Self {
-            type_name,
-            crate_name,
-            crate_module,
-            crate_version,
-            rustc_version,
-            generics,
-        } = self;
-
-        let generics = if generics.is_empty() {
-            String::new()
-        } else {
-            let generics = generics
-                .iter()
-                .map(|x| x.to_one_line_string())
-                .collect::<Vec<_>>();
-            format!("<{}>", generics.join("; "))
-        };
-        let mut s = type_name.clone() + &generics;
-        if let Some(crate_name) = crate_name {
-            s += ", Crate=";
-            s += crate_name;
-        }
-        if let Some(crate_module) = crate_module {
-            s += ", Module=";
-            s += crate_module;
-        }
-        if let Some(crate_version) = crate_version {
-            s += ", Version=";
-            s += &crate_version.to_string();
-        }
-        if let Some(rustc_version) = rustc_version {
-            s += ", Rustc=";
-            s += &rustc_version.to_string();
-        }
-        //    "{type_name}{generics}, Version={crate_version}, rustc={rustc_version}"
+        let mut s = String::new();
+        crate::one_line::write(self, &mut s);
+        s
+    }
+    /// Generates a one-line version, which is prefixed by the format version, e.g. "@1 Vec<…>, Crate=alloc"
+    /// Strings without prefix are parsed as version 1
+    pub fn to_versioned_one_line_string(&self) -> String {
+        let mut s = format!("@{} ", Self::ONE_LINE_FORMAT_VERSION);
+        crate::one_line::write(self, &mut s);
         s
     }
 
-    fn try_from_one_line_string(s: &str) -> Result<Self, (ParseError, String)> {
-        let mut tokens = vec![];
-        split_string(s, &mut tokens);
-        fn parse_type<'a, 'b>(
-            tokens: &'a [Token<'b>],
-        ) -> Result<(TypeInfo, &'a [Token<'b>]), (ParseError, String)> {
-            let (type_name, tokens) =
-                if let Some((Token::String(type_name), tokens)) = tokens.split_first() {
-                    (type_name.to_string(), tokens)
-                } else {
-                    return Err((ParseError::TypeNameNotFound, tokens.to_string()));
-                };
-            let mut crate_name = None;
-            let mut crate_module = None;
-            let mut crate_version = None;
-            let mut rustc_version = None;
-            let mut generics = Vec::new();
-            #[derive(PartialEq)]
-            enum GenericState {
-                Possible,
-                Ongoing,
-                Impossible,
-            }
-            let mut generic_state = GenericState::Possible;
-            let mut tokens = tokens;
-            while let Some((t, remaining)) = tokens.split_first() {
-                tokens = remaining;
-                match t {
-                    Token::String(s) => {
-                        generic_state = GenericState::Impossible;
-                        if let Some((s, data)) = s.split_once("Crate=") {
-                            if s.trim().is_empty() {
-                                if crate_name.is_none() {
-                                    crate_name = Some(data.trim().to_owned());
-                                } else {
-                                    return Err((
-                                        ParseError::CrateNameSetTwice,
-                                        tokens.to_string(),
-                                    ));
-                                }
-                            } else {
-                                return Err((
-                                    ParseError::FailedToParseCrateName,
-                                    tokens.to_string(),
-                                ));
-                            }
-                        } else if let Some((s, data)) = s.split_once("Module=") {
-                            if s.trim().is_empty() {
-                                if crate_module.is_none() {
-                                    crate_module = Some(data.trim().to_owned());
-                                } else {
-                                    return Err((
-                                        ParseError::CrateModuleSetTwice,
-                                        tokens.to_string(),
-                                    ));
-                                }
-                            } else {
-                                return Err((
-                                    ParseError::FailedToParseCrateModule,
-                                    tokens.to_string(),
-                                ));
-                            }
-                        } else if let Some((s, data)) = s.split_once("Version=") {
-                            if s.trim().is_empty() {
-                                if crate_version.is_none() {
-                                    crate_version = Some(data.trim().to_owned());
-                                } else {
-                                    return Err((
-                                        ParseError::CrateVersionSetTwice,
-                                        tokens.to_string(),
-                                    ));
-                                }
-                            } else {
-                                return Err((
-                                    ParseError::FailedToParseCrateVersion,
-                                    tokens.to_string(),
-                                ));
-                            }
-                        } else if let Some((s, data)) = s.split_once("Rustc=") {
-                            if s.trim().is_empty() {
-                                if rustc_version.is_none() {
-                                    rustc_version = Some(data.trim().to_owned());
-                                } else {
-                                    return Err((
-                                        ParseError::RustcVersionSetTwice,
-                                        tokens.to_string(),
-                                    ));
-                                }
-                            } else {
-                                return Err((
-                                    ParseError::FailedToParseRustcVersion,
-                                    tokens.to_string(),
-                                ));
-                            }
-                        } else {
-                            return Err((ParseError::UnexpectedData, tokens.to_string()));
-                        }
-                    }
-                    Token::GenericStart => {
-                        if generic_state != GenericState::Possible {
-                            return Err((ParseError::UnexpectedGenericStart, tokens.to_string()));
-                        }
-                        let (data, remaining) = parse_type(tokens)?;
-                        generics.push(data);
-                        tokens = remaining;
-                        generic_state = GenericState::Ongoing;
-                    }
-                    Token::GenericEnd => {
-                        if generic_state == GenericState::Ongoing {
-                            generic_state = GenericState::Impossible;
-                        } else {
-                            return Err((ParseError::UnexpectedGenericEnd, tokens.to_string()));
-                        }
-                    }
-                    Token::GenericNext => {
-                        if generic_state != GenericState::Ongoing {
-                            return Err((
-                                ParseError::UnexpectedGenericGenericNext,
-                                tokens.to_string(),
-                            ));
-                        }
-                        let (data, remaining) = parse_type(tokens)?;
-                        generics.push(data);
-                        tokens = remaining;
-                    }
-                    Token::Comma => generic_state = GenericState::Impossible,
-                }
-
-                if crate_name.is_some() && crate_version.is_some() && rustc_version.is_some() {
-                    break;
-                }
-            }
-            let crate_version = crate_version
-                .map(|v| semver::Version::from_str(&v))
-                .transpose()
-                .map_err(|e| {
-                    (
-                        ParseError::FailedToParseCrateVersionSemver(e),
-                        tokens.to_string(),
-                    )
-                })?;
-            let rustc_version = rustc_version
-                .map(|v| semver::Version::from_str(&v))
-                .transpose()
-                .map_err(|e| {
-                    (
-                        ParseError::FailedToParseRustcVersionSemver(e),
-                        tokens.to_string(),
-                    )
-                })?;
-            Ok((
-                TypeInfo {
-                    type_name,
-                    crate_name,
-                    crate_module,
-                    crate_version,
-                    rustc_version,
-                    generics,
-                },
-                tokens,
-            ))
-        }
+    /// Version of the binary format, which is generated by 'to_bytes'
+    pub const BINARY_FORMAT_VERSION: u8 = 1;
+    /// Generates a compact binary version, which starts with the format version
+    /// Each distinct text and version is stored only once, hence repeated crates, modules and versions of generics are cheap
+    /// Decoding the result via 'from_bytes' yields the original TypeInfo
+    pub fn to_bytes(&self) -> Vec<u8> {
+        crate::binary::write(self)
+    }
+    /// Decodes bytes, as generated by 'to_bytes', using the default options
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        Self::from_bytes_with_options(bytes, &ParseOptions::default())
+    }
+    /// Decodes bytes, as generated by 'to_bytes', with the given limits
+    /// The option 'strict' has no effect, since the binary format has no unknown entries
+    pub fn from_bytes_with_options(
+        bytes: &[u8],
+        options: &ParseOptions,
+    ) -> Result<Self, DecodeError> {
+        crate::binary::read(bytes, options)
+    }
+    /// Appends a generic type parameter, this is used while decoding
+    pub(crate) fn push_generic(&mut self, generic: TypeInfo) {
+        self.generics.push(generic);
+    }
+    /// Generates a one-line version in relative mode, which is prefixed by the format version, e.g. `@1+relative Vec<u8>, Crate=alloc`
+    /// Generics skip the 'Crate', 'Version' and 'Rustc' entries, if they equal those of the enclosing type
+    /// If the enclosing type has such an entry, but the generic has not, it is written without text, e.g. 'Crate='
+    /// Parsing the result via 'FromStr' restores the skipped entries and yields the original TypeInfo
+    pub fn to_relative_one_line_string(&self) -> String {
+        let mut s = format!(
+            "@{}{} ",
+            Self::ONE_LINE_FORMAT_VERSION,
+            crate::one_line::RELATIVE
+        );
+        crate::one_line::write_relative(self, &mut s);
+        s
+    }
+    /// Generates a URL-safe identifier, e.g. `typenaming:alloc@1.70.0/alloc/vec/Vec%3Ccore@1.70.0/core/primitive/u8;rustc=1.70.0%3E;rustc=1.70.0`
+    /// Decoding its percent-escapes once gives the readable form `typenaming:alloc@1.70.0/alloc/vec/Vec<core@1.70.0/core/primitive/u8;rustc=1.70.0>;rustc=1.70.0`
+    /// Besides the scheme, the identifier only contains 'A'-'Z', 'a'-'z', '0'-'9' and the characters '-', '.', '_', '~', '@', '/', ',', ';', '=' and '%'
+    /// This is not usable as file name, since the separators ':' and '/' are kept - see 'type_url_file_name'
+    /// Parsing the result via 'from_type_url' yields the original TypeInfo
+    pub fn type_url(&self) -> String {
+        crate::type_url::write(self)
+    }
+    /// Generates the type URL, in which the separators ':' and '/' are percent-encoded as well, e.g. `typenaming%3Aalloc@1.70.0%2Falloc%2Fvec%2FVec…`
+    /// Decoding its percent-escapes once gives the same readable form as for 'type_url'
+    /// This is usable as file name, but note that it is case-sensitive - 'mangled' is usable on case-insensitive file systems
+    /// Parsing the result via 'from_type_url' yields the original TypeInfo
+    pub fn type_url_file_name(&self) -> String {
+        crate::type_url::write_file_name(self)
+    }
+    /// Parses a type URL, as generated by 'type_url' or by 'type_url_file_name', using the default limits
+    pub fn from_type_url(s: &str) -> Result<Self, ParseError> {
+        crate::type_url::parse(s, &ParseOptions::default())
+    }
+    /// Version of the mangled format, which is generated by 'mangled'
+    pub const MANGLED_FORMAT_VERSION: u32 = 1;
+    /// Generates a reversible name, which only contains 'a'-'z', '0'-'9' and '_', e.g. '_t1_5__uvecc5_alloc…'
+    /// Texts are prefixed by their length, uppercase letters are written as '_u' followed by the lowercase letter, other characters than lowercase letters and digits as '__' for '_' or as '_{hex}_'
+    /// This is usable as identifier and as file name, also on case-insensitive file systems
+    /// Generics inherit crate and versions of the enclosing type, but crate, module and name of each type are included, e.g. `HashMap<String, Vec<u8>>` takes about 160 bytes
+    /// Hence deeply nested types may exceed the limit of 255 bytes, which most file systems impose on file names
+    /// Parsing the result via 'demangle' yields the original TypeInfo
+    pub fn mangled(&self) -> String {
+        crate::mangle::write(self)
+    }
+    /// Parses a mangled name, as generated by 'mangled', using the default limits
+    pub fn demangle(s: &str) -> Result<Self, ParseError> {
+        crate::mangle::demangle(s, &ParseOptions::default())
+    }
 
-        let (data, remaining) = parse_type(&tokens)?;
-        if remaining.is_empty() {
-            Ok(data)
-        } else {
-            Err((ParseError::RemainingToken(data), remaining.to_string()))
-        }
+    /// Parses a one-line string, as generated by 'to_one_line_string', with the given limits
+    /// 'FromStr' uses the default options
+    pub fn from_str_with_options(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        crate::one_line::parse(s, options)?.to_owned()
     }
 }
 
 impl FromStr for TypeInfo {
-    type Err = (ParseError, String);
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        TypeInfo::try_from_one_line_string(s)
+        TypeInfo::from_str_with_options(s, &ParseOptions::default())
     }
 }
 impl serde::Serialize for TypeInfo {
@@ -301,76 +251,244 @@ impl<'de> serde::Deserialize<'de> for TypeInfo {
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::Error;
-        let s = String::deserialize(deserializer)?;
-        TypeInfo::try_from_one_line_string(&s).map_err(|(e, tokens)| {
-            D::Error::custom(format!(
-                "Failed to parse due to error '{e:?}' with remaining tokens '{tokens}' and complete tokens '{s}'"
-            ))
-        })
+        // Human-readable formats also accept the structured map, see 'serde::structured'
+        crate::serde::one_line::deserialize(deserializer)
+    }
+}
+
+/// These options restrict the input, which is accepted by 'TypeInfo::from_str_with_options'
+/// This allows to parse strings from untrusted sources
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Maximal number of nested lists of generics, e.g. `Vec<Vec<u8>>` has depth 2 - defaults to 128
+    pub max_depth: usize,
+    /// Maximal length of the input in bytes - defaults to 1 MiB
+    pub max_length: usize,
+    /// Reject unknown entries instead of keeping them as extra entries - defaults to false
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_length: 1 << 20,
+            strict: false,
+        }
     }
 }
 
-fn split_string<'a>(s: &'a str, v: &mut Vec<Token<'a>>) {
-    if let Some((first, last)) = s.split_once(',') {
-        split_string(first, v);
-        v.push(Token::Comma);
-        split_string(last, v);
-    } else if let Some((first, last)) = s.split_once('<') {
-        split_string(first, v);
-        v.push(Token::GenericStart);
-        split_string(last, v);
-    } else if let Some((first, last)) = s.split_once('>') {
-        split_string(first, v);
-        v.push(Token::GenericEnd);
-        split_string(last, v);
-    } else if let Some((first, last)) = s.split_once(';') {
-        split_string(first, v);
-        v.push(Token::GenericNext);
-        split_string(last, v);
-    } else {
-        let s = s.trim();
-        if !s.is_empty() {
-            v.push(Token::String(s));
+/// This error is returned, if a string cannot be parsed into a TypeInfo, i.e. a one-line string, a type URL, a mangled name or Rust type syntax
+/// Besides the kind of error, it contains the parsed input, the byte offset of the error and the set of expected tokens
+#[derive(Debug, TypeNameable)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    input: String,
+    offset: usize,
+    expected: Vec<ExpectedToken>,
+}
+
+impl ParseError {
+    pub(crate) fn new(
+        kind: ParseErrorKind,
+        input: &str,
+        offset: usize,
+        expected: &[ExpectedToken],
+    ) -> Self {
+        Self {
+            kind,
+            input: input.to_owned(),
+            offset,
+            expected: expected.to_vec(),
         }
     }
+    /// Get kind of error
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.kind
+    }
+    /// Get the string, which failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+    /// Get byte offset into the input, at which the error occurred
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Get set of tokens, which would have been valid at the error's offset
+    /// This is empty, if the error is not caused by an unexpected token, e.g. for an invalid semver version
+    pub fn expected(&self) -> &[ExpectedToken] {
+        &self.expected
+    }
+    /// Renders the line of the input, which contains the error, followed by a line with a caret pointing at the error
+    pub fn render_caret(&self) -> String {
+        let line_start = self.input[..self.offset]
+            .rfind('\n')
+            .map(|x| x + 1)
+            .unwrap_or(0);
+        let line_end = self.input[self.offset..]
+            .find('\n')
+            .map(|x| x + self.offset)
+            .unwrap_or(self.input.len());
+        let column = self.input[line_start..self.offset].chars().count();
+        format!(
+            "{}\n{}^ {}",
+            &self.input[line_start..line_end],
+            " ".repeat(column),
+            self
+        )
+    }
 }
 
-#[derive(Debug)]
-pub enum ParseError {
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)?;
+        if let Some((last, others)) = self.expected.split_last() {
+            f.write_str(", expected ")?;
+            for (i, expected) in others.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{expected}")?;
+            }
+            if !others.is_empty() {
+                f.write_str(" or ")?;
+            }
+            write!(f, "{last}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::FailedToParseCrateVersionSemver(e)
+            | ParseErrorKind::FailedToParseRustcVersionSemver(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// This describes, why a string cannot be parsed into a TypeInfo
+#[derive(Debug, TypeNameable)]
+pub enum ParseErrorKind {
+    /// The string does not start with a type name
     TypeNameNotFound,
-    RemainingToken(TypeInfo),
+    /// A complete type was parsed, but some tokens are left
+    RemainingToken(Box<TypeInfo>),
+    /// A '>' was found, which does not close a list of generics
     UnexpectedGenericEnd,
+    /// A ';' was found outside of a list of generics
     UnexpectedGenericGenericNext,
+    /// A '<' was found, which does not directly follow a type name
     UnexpectedGenericStart,
+    /// The 'Crate=' entry is malformed
     FailedToParseCrateName,
+    /// The 'Crate=' entry is given twice
     CrateNameSetTwice,
+    /// The 'Module=' entry is malformed
     FailedToParseCrateModule,
+    /// The 'Module=' entry is given twice
     CrateModuleSetTwice,
+    /// The 'Version=' entry is malformed
     FailedToParseCrateVersion,
+    /// The 'Version=' entry is given twice
     CrateVersionSetTwice,
+    /// The 'Rustc=' entry is malformed
     FailedToParseRustcVersion,
+    /// The 'Rustc=' entry is given twice
     RustcVersionSetTwice,
+    /// An entry was found, which is not known, or some other unexpected data was found
     UnexpectedData,
+    /// The value of the 'Version=' entry is not a semver version
     FailedToParseCrateVersionSemver(semver::Error),
+    /// The value of the 'Rustc=' entry is not a semver version
     FailedToParseRustcVersionSemver(semver::Error),
+    /// A list of generics is not closed by a '>'
+    MissingGenericEnd,
+    /// An escape sequence is invalid, e.g. a backslash in a one-line string or a percent sign in a type URL
+    InvalidEscape,
+    /// The format version, e.g. '@1' of a one-line string or '_t1_' of a mangled name, is unknown
+    UnsupportedFormatVersion,
+    /// The generics are nested deeper than allowed by 'ParseOptions::max_depth'
+    NestingTooDeep,
+    /// The input is longer than allowed by 'ParseOptions::max_length'
+    InputTooLong,
+    /// An unknown entry is given twice
+    ExtraSetTwice,
+    /// A type URL does not start with 'typenaming:'
+    MissingTypeUrlScheme,
+    /// A mangled name does not start with '_T'
+    MissingMangledPrefix,
 }
-trait TokensToString {
-    fn to_string(&self) -> String;
-}
-impl<'b> TokensToString for [Token<'b>] {
-    fn to_string(&self) -> String {
-        let mut tt = Default::default();
-        for t in self {
-            match t {
-                Token::String(s) => tt += *s,
-                Token::GenericStart => tt += "<",
-                Token::GenericEnd => tt += ">",
-                Token::Comma => tt += ", ",
-                Token::GenericNext => tt += "; ",
-            }
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TypeNameNotFound => f.write_str("type name not found"),
+            Self::RemainingToken(_) => f.write_str("unexpected data after complete type"),
+            Self::UnexpectedGenericEnd => f.write_str("unexpected '>'"),
+            Self::UnexpectedGenericGenericNext => f.write_str("unexpected ';'"),
+            Self::UnexpectedGenericStart => f.write_str("unexpected '<'"),
+            Self::FailedToParseCrateName => f.write_str("malformed 'Crate' entry"),
+            Self::CrateNameSetTwice => f.write_str("duplicate 'Crate' entry"),
+            Self::FailedToParseCrateModule => f.write_str("malformed 'Module' entry"),
+            Self::CrateModuleSetTwice => f.write_str("duplicate 'Module' entry"),
+            Self::FailedToParseCrateVersion => f.write_str("malformed 'Version' entry"),
+            Self::CrateVersionSetTwice => f.write_str("duplicate 'Version' entry"),
+            Self::FailedToParseRustcVersion => f.write_str("malformed 'Rustc' entry"),
+            Self::RustcVersionSetTwice => f.write_str("duplicate 'Rustc' entry"),
+            Self::UnexpectedData => f.write_str("unexpected data"),
+            Self::FailedToParseCrateVersionSemver(e) => write!(f, "invalid crate version: {e}"),
+            Self::FailedToParseRustcVersionSemver(e) => write!(f, "invalid rustc version: {e}"),
+            Self::MissingGenericEnd => f.write_str("missing '>'"),
+            Self::InvalidEscape => f.write_str("invalid escape sequence"),
+            Self::UnsupportedFormatVersion => f.write_str("unsupported format version"),
+            Self::NestingTooDeep => f.write_str("generics nested too deeply"),
+            Self::InputTooLong => f.write_str("input too long"),
+            Self::ExtraSetTwice => f.write_str("duplicate entry"),
+            Self::MissingTypeUrlScheme => f.write_str("missing 'typenaming:' scheme"),
+            Self::MissingMangledPrefix => f.write_str("missing '_T' prefix"),
         }
-        tt
+    }
+}
+
+/// This represents a token, which was expected by the parser of the one-line format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TypeNameable)]
+pub enum ExpectedToken {
+    /// The name of a type
+    TypeName,
+    /// An entry key, which may be any text, since unknown entries are kept as extra entries
+    Key,
+    /// One of the entry keys 'Crate', 'Module', 'Version' and 'Rustc', which are the only keys accepted by strict parsing
+    KnownKey,
+    /// A '<', which starts a list of generics
+    GenericStart,
+    /// A ';', which separates generics
+    GenericNext,
+    /// A '>', which closes a list of generics
+    GenericEnd,
+    /// A ',', which starts an entry
+    Comma,
+    /// A '=', which separates an entry's key and value
+    Equals,
+    /// The end of the input
+    End,
+}
+
+impl std::fmt::Display for ExpectedToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::TypeName => "type name",
+            Self::Key => "entry key",
+            Self::KnownKey => "'Crate', 'Module', 'Version' or 'Rustc'",
+            Self::GenericStart => "'<'",
+            Self::GenericNext => "';'",
+            Self::GenericEnd => "'>'",
+            Self::Comma => "','",
+            Self::Equals => "'='",
+            Self::End => "end of input",
+        })
     }
 }
 
@@ -391,6 +509,7 @@ mod serde_tests {
                 build: semver::BuildMetadata::from_str("ieieiei").unwrap(),
             }),
             generics: vec![],
+            extra: BTreeMap::new(),
         }
     }
     fn generic_example() -> TypeInfo {
@@ -407,6 +526,7 @@ mod serde_tests {
                 build: semver::BuildMetadata::from_str("ieieiei").unwrap(),
             }),
             generics: vec![simple_example()],
+            extra: BTreeMap::new(),
         }
     }
     fn generic2_example() -> TypeInfo {
@@ -423,6 +543,7 @@ mod serde_tests {
                 build: semver::BuildMetadata::from_str("ieieiei").unwrap(),
             }),
             generics: vec![simple_example(), generic_example(), simple_example()],
+            extra: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use crate::{ParseError, ParseErrorKind, ParseOptions, TypeInfo, TypeNameable};
+
+/// This type is a borrowed view of a one-line string, as generated by 'TypeInfo::to_one_line_string'
+/// Texts borrow from the input, unless they contain escape sequences
+/// Versions are only parsed, if they are requested, hence checking the type name does not allocate
+#[derive(Debug, Clone, TypeNameable)]
+pub struct TypeInfoRef<'a> {
+    pub(crate) input: &'a str,
+    pub(crate) type_name: Cow<'a, str>,
+    pub(crate) crate_name: Option<Cow<'a, str>>,
+    pub(crate) crate_module: Option<Cow<'a, str>>,
+    pub(crate) crate_version: Option<RawVersion<'a>>,
+    pub(crate) rustc_version: Option<RawVersion<'a>>,
+    pub(crate) generics: Vec<TypeInfoRef<'a>>,
+    pub(crate) extra: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+    /// Entries, which are still to be inherited from the enclosing type, see 'one_line::INHERIT_CRATE'
+    pub(crate) inherit: u8,
+}
+
+/// This is an unparsed version, together with its byte offset into the input
+#[derive(Debug, Clone)]
+pub(crate) struct RawVersion<'a> {
+    pub(crate) offset: usize,
+    pub(crate) text: Cow<'a, str>,
+}
+
+impl<'a> TypeInfoRef<'a> {
+    /// Parses a one-line string, using the default options
+    pub fn parse(s: &'a str) -> Result<Self, ParseError> {
+        Self::parse_with_options(s, &ParseOptions::default())
+    }
+    /// Parses a one-line string with the given limits
+    pub fn parse_with_options(s: &'a str, options: &ParseOptions) -> Result<Self, ParseError> {
+        crate::one_line::parse(s, options)
+    }
+    /// Get name of type
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+    /// Get name of crate, in which the type is contained
+    pub fn crate_name(&self) -> Option<&str> {
+        self.crate_name.as_deref()
+    }
+    /// Get module of crate, in which the type is contained
+    pub fn crate_module(&self) -> Option<&str> {
+        self.crate_module.as_deref()
+    }
+    /// Get version of crate, in which the type is contained - if available
+    /// The version is parsed on each call
+    pub fn crate_version(&self) -> Result<Option<::semver::Version>, ParseError> {
+        self.parse_version(
+            &self.crate_version,
+            ParseErrorKind::FailedToParseCrateVersionSemver,
+        )
+    }
+    /// Get version of rustc, which was used to compile the type's crate - if available
+    /// The version is parsed on each call
+    pub fn rustc_version(&self) -> Result<Option<::semver::Version>, ParseError> {
+        self.parse_version(
+            &self.rustc_version,
+            ParseErrorKind::FailedToParseRustcVersionSemver,
+        )
+    }
+    /// Get unparsed version of crate, in which the type is contained - if available
+    pub fn crate_version_str(&self) -> Option<&str> {
+        self.crate_version.as_ref().map(|x| x.text.as_ref())
+    }
+    /// Get unparsed version of rustc, which was used to compile the type's crate - if available
+    pub fn rustc_version_str(&self) -> Option<&str> {
+        self.rustc_version.as_ref().map(|x| x.text.as_ref())
+    }
+    /// Get list of generic type parameters of the given type
+    pub fn generics(&self) -> &[TypeInfoRef<'a>] {
+        &self.generics
+    }
+    /// Get entries, which are not known to this version of typenaming, in the order of the input
+    pub fn extra(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.extra.iter().map(|(k, v)| (k.as_ref(), v.as_ref()))
+    }
+    /// Converts into an owned TypeInfo
+    /// This fails, if a version is not a semver version
+    pub fn to_owned(&self) -> Result<TypeInfo, ParseError> {
+        Ok(TypeInfo::new(
+            self.type_name.clone().into_owned(),
+            self.crate_name.clone().map(Cow::into_owned),
+            self.crate_module.clone().map(Cow::into_owned),
+            self.crate_version()?,
+            self.rustc_version()?,
+            self.generics
+                .iter()
+                .map(TypeInfoRef::to_owned)
+                .collect::<Result<_, _>>()?,
+        )
+        .with_extra(
+            self.extra()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect(),
+        ))
+    }
+
+    fn parse_version(
+        &self,
+        version: &Option<RawVersion>,
+        error: fn(semver::Error) -> ParseErrorKind,
+    ) -> Result<Option<::semver::Version>, ParseError> {
+        version
+            .as_ref()
+            .map(|RawVersion { offset, text }| {
+                semver::Version::from_str(text)
+                    .map_err(|e| ParseError::new(error(e), self.input, *offset, &[]))
+            })
+            .transpose()
+    }
+}
+
+/// Compares with an owned TypeInfo, an unparsable version is never equal
+impl PartialEq<TypeInfo> for TypeInfoRef<'_> {
+    fn eq(&self, other: &TypeInfo) -> bool {
+        self.type_name() == other.type_name()
+            && self.crate_name() == other.crate_name().as_deref()
+            && self.crate_module() == other.crate_module().as_deref()
+            && self.crate_version().ok().as_ref() == Some(other.crate_version())
+            && self.rustc_version().ok().as_ref() == Some(other.rustc_version())
+            && self.extra.len() == other.extra().len()
+            && self
+                .extra()
+                .all(|(k, v)| other.extra().get(k).map(String::as_str) == Some(v))
+            && self.generics.len() == other.generics().len()
+            && self
+                .generics
+                .iter()
+                .zip(other.generics())
+                .all(|(a, b)| a == b)
+    }
+}
+
+/// Compares with a borrowed view, an unparsable version is never equal
+impl PartialEq<TypeInfoRef<'_>> for TypeInfo {
+    fn eq(&self, other: &TypeInfoRef) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{ParseErrorKind, TypeInfo, TypeInfoRef, TypeNameable};
+
+    #[test]
+    fn borrowed() {
+        let s = "Vec<u8, Crate=core>, Crate=alloc, Module=alloc::vec, Version=1.70.0";
+        let info = TypeInfoRef::parse(s).unwrap();
+        assert_eq!("Vec", info.type_name());
+        assert_eq!(Some("alloc::vec"), info.crate_module());
+        assert_eq!(Some("1.70.0"), info.crate_version_str());
+        assert_eq!(None, info.rustc_version().unwrap());
+        assert_eq!(Some("core"), info.generics()[0].crate_name());
+        assert!(matches!(info.type_name, Cow::Borrowed(_)));
+        assert!(matches!(info.crate_module, Some(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn escaped() {
+        let s = "a\\<b\\>, Module=x\\=y";
+        let info = TypeInfoRef::parse(s).unwrap();
+        assert_eq!("a<b>", info.type_name());
+        assert_eq!(Some("x=y"), info.crate_module());
+        assert!(matches!(info.type_name, Cow::Owned(_)));
+    }
+
+    #[test]
+    fn extra() {
+        let info = TypeInfoRef::parse("Test, Size=24, Crate=x, Colour=red").unwrap();
+        assert_eq!(
+            vec![("Size", "24"), ("Colour", "red")],
+            info.extra().collect::<Vec<_>>()
+        );
+        let owned = info.to_owned().unwrap();
+        assert_eq!(2, owned.extra().len());
+        assert_eq!(info, owned);
+        assert_ne!(info, owned.with_extra(Default::default()));
+    }
+
+    #[test]
+    fn lazy_version() {
+        let info = TypeInfoRef::parse("Test, Version=one, Rustc=1.70.0").unwrap();
+        assert_eq!("Test", info.type_name());
+        let error = info.crate_version().unwrap_err();
+        assert!(matches!(
+            error.kind(),
+            ParseErrorKind::FailedToParseCrateVersionSemver(_)
+        ));
+        assert_eq!(14, error.offset());
+        assert!(info.to_owned().is_err());
+        assert_eq!(
+            Some(semver::Version::new(1, 70, 0)),
+            info.rustc_version().unwrap()
+        );
+    }
+
+    #[test]
+    fn to_owned() {
+        let expected = Vec::<Result<u8, String>>::type_info();
+        let s = expected.to_one_line_string();
+        let info = TypeInfoRef::parse(&s).unwrap();
+        assert_eq!(expected, info.to_owned().unwrap());
+        assert_eq!(info, expected);
+        assert_eq!(expected, info);
+        assert_ne!(info, TypeInfo::new_const_argument("Vec".to_owned()));
+    }
+}
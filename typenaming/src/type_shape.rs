@@ -0,0 +1,193 @@
+#[cfg(any(feature = "ts", feature = "json-schema"))]
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::TypeInfo;
+
+/// This resolves a type, which is referenced by a field, to its TypeInfo and its shape
+/// It is a function, so that recursive types can be described
+pub type TypeReference = fn() -> (TypeInfo, Option<TypeShape>);
+
+/// This describes the fields of a struct or the variants of an enum
+/// It is generated by deriving TypeNameable with the argument '#[typenameable(fields)]'
+#[derive(Debug, Clone)]
+pub enum TypeShape {
+    /// A struct with the given fields
+    Struct(Fields),
+    /// An enum with the given variants, in the order of declaration
+    Enum(Vec<Variant>),
+}
+
+/// This describes the fields of a struct or of an enum variant
+#[derive(Debug, Clone)]
+pub enum Fields {
+    /// There are no fields, e.g. 'struct Unit;'
+    Unit,
+    /// Fields are named, e.g. 'struct Point { x: f32, y: f32 }'
+    Named(Vec<Field>),
+    /// Fields are unnamed, e.g. 'struct Point(f32, f32);'
+    Unnamed(Vec<Field>),
+}
+
+/// This describes a single field of a struct or of an enum variant
+#[derive(Debug, Clone)]
+pub struct Field {
+    name: Option<String>,
+    type_info: TypeInfo,
+    references: Vec<TypeReference>,
+}
+
+impl Fields {
+    /// Get fields, which is empty for 'Unit'
+    pub fn iter(&self) -> std::slice::Iter<'_, Field> {
+        match self {
+            Fields::Unit => [].iter(),
+            Fields::Named(fields) | Fields::Unnamed(fields) => fields.iter(),
+        }
+    }
+}
+
+impl Field {
+    /// Constructor, 'references' resolve the field's type and all types contained in it, e.g. `Vec<User>` and 'User'
+    pub fn new(name: Option<String>, type_info: TypeInfo, references: Vec<TypeReference>) -> Self {
+        Self {
+            name,
+            type_info,
+            references,
+        }
+    }
+    /// Get name of field - if the field is named
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    /// Get type of field
+    pub fn type_info(&self) -> &TypeInfo {
+        &self.type_info
+    }
+    /// Get types, which are contained in the field's type, including the field's type itself
+    pub fn references(&self) -> &[TypeReference] {
+        &self.references
+    }
+}
+
+/// This describes a single variant of an enum
+#[derive(Debug, Clone)]
+pub struct Variant {
+    name: String,
+    fields: Fields,
+}
+
+impl Variant {
+    /// Constructor
+    pub fn new(name: String, fields: Fields) -> Self {
+        Self { name, fields }
+    }
+    /// Get name of variant
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Get fields of variant
+    pub fn fields(&self) -> &Fields {
+        &self.fields
+    }
+}
+
+/// Collects the given type and all types with shape, which are referenced by its fields, in the order of their first appearance
+/// Types without shape are skipped, each type is collected once, hence recursive types terminate
+pub(crate) fn collect_shapes(
+    info: TypeInfo,
+    shape: Option<TypeShape>,
+) -> Vec<(TypeInfo, TypeShape)> {
+    let mut shapes = Vec::new();
+    let mut known = HashSet::new();
+    let mut pending = vec![(info, shape)];
+    while let Some((info, shape)) = pending.pop() {
+        let Some(shape) = shape else { continue };
+        if !known.insert(info.clone()) {
+            continue;
+        }
+        let fields: Vec<&Field> = match &shape {
+            TypeShape::Struct(fields) => fields.iter().collect(),
+            TypeShape::Enum(variants) => variants
+                .iter()
+                .flat_map(|variant| variant.fields().iter())
+                .collect(),
+        };
+        // Pushed in reverse, so that referenced types are collected in the order of the fields
+        let references: Vec<_> = fields
+            .iter()
+            .flat_map(|field| field.references())
+            .map(|reference| reference())
+            .collect();
+        pending.extend(references.into_iter().rev());
+        shapes.push((info, shape));
+    }
+    shapes
+}
+
+/// Generic instances are declared separately, their generics are appended to the name, e.g. 'Page_User' for `Page<User>`
+#[cfg(any(feature = "ts", feature = "json-schema"))]
+pub(crate) fn declaration_name(info: &TypeInfo) -> String {
+    let mut name = identifier(info.type_name());
+    for generic in info.generics() {
+        name.push('_');
+        name.push_str(&declaration_name(generic));
+    }
+    name
+}
+
+/// Assigns a unique declaration name to each collected type
+/// Colliding names are qualified by the module, e.g. 'api_Error' and 'db_Error' for two types named 'Error'
+/// If the qualified names collide as well, e.g. for two versions of a crate, the position of the type is appended
+#[cfg(any(feature = "ts", feature = "json-schema"))]
+pub(crate) fn declaration_names(shapes: &[(TypeInfo, TypeShape)]) -> HashMap<TypeInfo, String> {
+    let count = |names: &[String], name: &String| names.iter().filter(|x| *x == name).count();
+    let names: Vec<String> = shapes
+        .iter()
+        .map(|(info, _)| declaration_name(info))
+        .collect();
+    let names: Vec<String> = shapes
+        .iter()
+        .zip(&names)
+        .map(|((info, _), name)| match count(&names, name) {
+            1 => name.clone(),
+            _ => qualified_name(info),
+        })
+        .collect();
+    shapes
+        .iter()
+        .zip(&names)
+        .enumerate()
+        .map(|(i, ((info, _), name))| match count(&names, name) {
+            1 => (info.clone(), name.clone()),
+            _ => (info.clone(), format!("{name}_{i}")),
+        })
+        .collect()
+}
+
+/// This is the declaration name, where the type and its generics are prefixed by their module, e.g. 'api_Error'
+#[cfg(any(feature = "ts", feature = "json-schema"))]
+fn qualified_name(info: &TypeInfo) -> String {
+    let mut name = String::new();
+    for segment in info
+        .crate_module()
+        .iter()
+        .flat_map(|module| module.split("::"))
+    {
+        name.push_str(&identifier(segment));
+        name.push('_');
+    }
+    name.push_str(&identifier(info.type_name()));
+    for generic in info.generics() {
+        name.push('_');
+        name.push_str(&qualified_name(generic));
+    }
+    name
+}
+
+#[cfg(any(feature = "ts", feature = "json-schema"))]
+fn identifier(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
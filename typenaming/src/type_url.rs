@@ -0,0 +1,400 @@
+//! Writer and parser of type URLs, as generated by 'TypeInfo::type_url'
+//!
+//! The grammar of a type URL, after decoding its percent-escapes once, is
+//!     type_url := 'typenaming:' type
+//!     type     := [ text ] [ '@' text ] { '/' text } '/' text [ '<' type { ',' type } '>' ] { ';' key '=' text }
+//!     key      := 'crate' | 'rustc' | 'x-' text
+//! The optional texts before and after the '@' are the crate and its version
+//! The texts separated by '/' are the segments of the module, followed by the type name
+//! The 'crate' entry is only used for an empty crate name, 'rustc' gives the rustc version and 'x-…' the extra entries
+//! Texts are percent-encoded, except for the unreserved characters 'A'-'Z', 'a'-'z', '0'-'9', '-', '.', '_' and '~'
+//! Finally, '<', '>' and '%' are percent-encoded, hence the type URL only contains unreserved characters and ':', '@', '/', ',', ';', '=' and '%'
+//! For file names, the separators ':' and '/' are percent-encoded as well, which the parser accepts in place of the separators
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::{ExpectedToken, ParseError, ParseErrorKind, ParseOptions, TypeInfo};
+
+pub(crate) const SCHEME: &str = "typenaming:";
+const CRATE: &str = "crate";
+const RUSTC: &str = "rustc";
+const EXTRA: &str = "x-";
+const GENERIC_START: &str = "%3C";
+const GENERIC_END: &str = "%3E";
+const SEGMENT: &str = "/";
+const SCHEME_FILE_NAME: &str = "typenaming%3A";
+const SEGMENT_FILE_NAME: &str = "%2F";
+/// This prefixes an escaped byte of a text, it is the percent-encoded '%'
+const ESCAPE: &str = "%25";
+
+/// Writes a type URL, including the scheme
+pub(crate) fn write(info: &TypeInfo) -> String {
+    let mut out = SCHEME.to_owned();
+    write_type(info, &mut out);
+    out
+}
+
+/// Writes a type URL, which is usable as file name
+/// Texts percent-encode their ':' and '/', so all remaining ones are separators
+pub(crate) fn write_file_name(info: &TypeInfo) -> String {
+    write(info)
+        .replace(':', "%3A")
+        .replace(SEGMENT, SEGMENT_FILE_NAME)
+}
+
+fn write_type(info: &TypeInfo, out: &mut String) {
+    if let Some(krate) = info.crate_name() {
+        escape(krate, out);
+    }
+    if let Some(version) = info.crate_version() {
+        out.push('@');
+        escape(&version.to_string(), out);
+    }
+    if let Some(module) = info.crate_module() {
+        for segment in module.split("::") {
+            out.push('/');
+            escape(segment, out);
+        }
+    }
+    out.push('/');
+    escape(info.type_name(), out);
+    if let Some((first, others)) = info.generics().split_first() {
+        out.push_str(GENERIC_START);
+        write_type(first, out);
+        for generic in others {
+            out.push(',');
+            write_type(generic, out);
+        }
+        out.push_str(GENERIC_END);
+    }
+    if info.crate_name().as_deref() == Some("") {
+        write!(out, ";{CRATE}=").expect("Writing to a String cannot fail");
+    }
+    if let Some(version) = info.rustc_version() {
+        write!(out, ";{RUSTC}=").expect("Writing to a String cannot fail");
+        escape(&version.to_string(), out);
+    }
+    for (key, value) in info.extra() {
+        write!(out, ";{EXTRA}").expect("Writing to a String cannot fail");
+        escape(key, out);
+        out.push('=');
+        escape(value, out);
+    }
+}
+
+fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+fn escape(text: &str, out: &mut String) {
+    for &b in text.as_bytes() {
+        if is_unreserved(b) {
+            out.push(b as char);
+        } else {
+            write!(out, "{ESCAPE}{b:02X}").expect("Writing to a String cannot fail");
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+    options: &'a ParseOptions,
+}
+
+impl Parser<'_> {
+    fn error(&self, kind: ParseErrorKind, expected: &[ExpectedToken]) -> ParseError {
+        ParseError::new(kind, self.input, self.position, expected)
+    }
+
+    fn rest(&self) -> &str {
+        &self.input[self.position..]
+    }
+
+    /// Consumes the given delimiter, percent-escapes are matched case-insensitively
+    fn eat(&mut self, delimiter: &str) -> bool {
+        let rest = self.rest().as_bytes();
+        let found = rest.len() >= delimiter.len()
+            && rest[..delimiter.len()].eq_ignore_ascii_case(delimiter.as_bytes());
+        if found {
+            self.position += delimiter.len();
+        }
+        found
+    }
+
+    fn text(&mut self) -> Result<String, ParseError> {
+        let start = self.position;
+        let mut bytes = Vec::new();
+        loop {
+            let next = self.rest().as_bytes().first().copied();
+            match next {
+                Some(b) if is_unreserved(b) => {
+                    bytes.push(b);
+                    self.position += 1;
+                }
+                Some(b'%') if self.eat(ESCAPE) => {
+                    let byte = self
+                        .rest()
+                        .get(..2)
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                        .ok_or_else(|| self.error(ParseErrorKind::InvalidEscape, &[]))?;
+                    bytes.push(byte);
+                    self.position += 2;
+                }
+                _ => break,
+            }
+        }
+        String::from_utf8(bytes)
+            .map_err(|_| ParseError::new(ParseErrorKind::InvalidEscape, self.input, start, &[]))
+    }
+
+    fn version(
+        &mut self,
+        error: fn(semver::Error) -> ParseErrorKind,
+    ) -> Result<semver::Version, ParseError> {
+        let start = self.position;
+        let text = self.text()?;
+        text.parse()
+            .map_err(|e| ParseError::new(error(e), self.input, start, &[]))
+    }
+
+    fn parse_type(&mut self, depth: usize) -> Result<TypeInfo, ParseError> {
+        let mut crate_name = Some(self.text()?).filter(|x| !x.is_empty());
+        let crate_version = if self.eat("@") {
+            Some(self.version(ParseErrorKind::FailedToParseCrateVersionSemver)?)
+        } else {
+            None
+        };
+        let mut segments = Vec::new();
+        while self.eat(SEGMENT) || self.eat(SEGMENT_FILE_NAME) {
+            segments.push(self.text()?);
+        }
+        let type_name = segments.pop().ok_or_else(|| {
+            self.error(ParseErrorKind::TypeNameNotFound, &[ExpectedToken::TypeName])
+        })?;
+        let crate_module = Some(segments)
+            .filter(|x| !x.is_empty())
+            .map(|x| x.join("::"));
+
+        let mut generics = Vec::new();
+        if self.eat(GENERIC_START) {
+            if depth >= self.options.max_depth {
+                self.position -= GENERIC_START.len();
+                return Err(self.error(ParseErrorKind::NestingTooDeep, &[]));
+            }
+            loop {
+                generics.push(self.parse_type(depth + 1)?);
+                if self.eat(",") {
+                    continue;
+                }
+                if self.eat(GENERIC_END) {
+                    break;
+                }
+                let kind = if self.rest().is_empty() {
+                    ParseErrorKind::MissingGenericEnd
+                } else {
+                    ParseErrorKind::UnexpectedData
+                };
+                let expected = [ExpectedToken::GenericNext, ExpectedToken::GenericEnd];
+                return Err(self.error(kind, &expected));
+            }
+        }
+
+        let mut rustc_version = None;
+        let mut extra = BTreeMap::new();
+        while self.eat(";") {
+            let start = self.position;
+            let key = self.text()?;
+            if !self.eat("=") {
+                return Err(self.error(ParseErrorKind::UnexpectedData, &[ExpectedToken::Equals]));
+            }
+            let input = self.input;
+            let set_twice = |kind| ParseError::new(kind, input, start, &[]);
+            match key.as_str() {
+                CRATE => {
+                    if crate_name.is_some() {
+                        return Err(set_twice(ParseErrorKind::CrateNameSetTwice));
+                    }
+                    crate_name = Some(self.text()?);
+                }
+                RUSTC => {
+                    if rustc_version.is_some() {
+                        return Err(set_twice(ParseErrorKind::RustcVersionSetTwice));
+                    }
+                    rustc_version =
+                        Some(self.version(ParseErrorKind::FailedToParseRustcVersionSemver)?);
+                }
+                _ => match key.strip_prefix(EXTRA) {
+                    Some(key) => {
+                        if extra.insert(key.to_owned(), self.text()?).is_some() {
+                            return Err(set_twice(ParseErrorKind::ExtraSetTwice));
+                        }
+                    }
+                    None => {
+                        return Err(ParseError::new(
+                            ParseErrorKind::UnexpectedData,
+                            self.input,
+                            start,
+                            &[ExpectedToken::Key],
+                        ))
+                    }
+                },
+            }
+        }
+        Ok(TypeInfo::new(
+            type_name,
+            crate_name,
+            crate_module,
+            crate_version,
+            rustc_version,
+            generics,
+        )
+        .with_extra(extra))
+    }
+}
+
+/// Parses a type URL, including the scheme
+pub(crate) fn parse(input: &str, options: &ParseOptions) -> Result<TypeInfo, ParseError> {
+    if input.len() > options.max_length {
+        let offset = (0..=options.max_length)
+            .rev()
+            .find(|&i| input.is_char_boundary(i))
+            .unwrap_or(0);
+        return Err(ParseError::new(
+            ParseErrorKind::InputTooLong,
+            input,
+            offset,
+            &[],
+        ));
+    }
+    let mut parser = Parser {
+        input,
+        position: 0,
+        options,
+    };
+    if !parser.eat(SCHEME) && !parser.eat(SCHEME_FILE_NAME) {
+        return Err(parser.error(ParseErrorKind::MissingTypeUrlScheme, &[]));
+    }
+    let info = parser.parse_type(0)?;
+    if !parser.rest().is_empty() {
+        return Err(parser.error(
+            ParseErrorKind::RemainingToken(Box::new(info)),
+            &[ExpectedToken::End],
+        ));
+    }
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseErrorKind, TypeInfo, TypeNameable};
+
+    fn roundtrip(info: &TypeInfo) {
+        let url = info.type_url();
+        let allowed = |c: char| c.is_ascii_alphanumeric() || "-._~:@/,;=%".contains(c);
+        assert!(url.chars().all(allowed), "{url}");
+        assert_eq!(*info, TypeInfo::from_type_url(&url).unwrap(), "{url}");
+        let file_name = info.type_url_file_name();
+        let allowed = |c: char| c.is_ascii_alphanumeric() || "-._~@,;=%".contains(c);
+        assert!(file_name.chars().all(allowed), "{file_name}");
+        assert_eq!(
+            *info,
+            TypeInfo::from_type_url(&file_name).unwrap(),
+            "{file_name}"
+        );
+    }
+
+    #[test]
+    fn readable() {
+        let info = TypeInfo::new(
+            "Gen".to_owned(),
+            Some("testing".to_owned()),
+            Some("testing::types".to_owned()),
+            Some(semver::Version::new(1, 2, 3)),
+            None,
+            vec![TypeInfo::new_const_argument("3".to_owned())],
+        );
+        assert_eq!(
+            "typenaming:testing@1.2.3/testing/types/Gen%3C/3%3E",
+            info.type_url()
+        );
+        assert_eq!(
+            "typenaming%3Atesting@1.2.3%2Ftesting%2Ftypes%2FGen%3C%2F3%3E",
+            info.type_url_file_name()
+        );
+        roundtrip(&info);
+    }
+
+    #[test]
+    fn roundtrips() {
+        roundtrip(&Vec::<Result<u8, String>>::type_info());
+        roundtrip(&<[Vec<u8>; 3]>::type_info());
+        roundtrip(&TypeInfo::from_rust_type_str("dyn core::any::Any + Send").unwrap());
+        roundtrip(&TypeInfo::from_rust_type_str("fn(u8) -> alloc::string::String").unwrap());
+        roundtrip(
+            &TypeInfo::new(
+                "T<ä>%3C".to_owned(),
+                Some(String::new()),
+                Some(String::new()),
+                Some("1.0.0-alpha.1+build.5".parse().unwrap()),
+                Some(semver::Version::new(1, 70, 0)),
+                vec![TypeInfo::new(
+                    String::new(),
+                    None,
+                    Some("a::::b".to_owned()),
+                    None,
+                    None,
+                    vec![],
+                )],
+            )
+            .with_extra(
+                [("crate", "x"), ("", ""), ("a;b", "c/d")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                    .collect(),
+            ),
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let error = |s: &str| TypeInfo::from_type_url(s).unwrap_err();
+        assert!(matches!(
+            error("alloc/Vec").kind(),
+            ParseErrorKind::MissingTypeUrlScheme
+        ));
+        assert!(matches!(
+            error("typenaming:alloc").kind(),
+            ParseErrorKind::TypeNameNotFound
+        ));
+        assert!(matches!(
+            error("typenaming:/Vec%3C/u8").kind(),
+            ParseErrorKind::MissingGenericEnd
+        ));
+        assert!(matches!(
+            error("typenaming:/Vec%3C/u8%3E%3E").kind(),
+            ParseErrorKind::RemainingToken(_)
+        ));
+        assert!(matches!(
+            error("typenaming:/a%25G0").kind(),
+            ParseErrorKind::InvalidEscape
+        ));
+        assert!(matches!(
+            error("typenaming:x@one/a").kind(),
+            ParseErrorKind::FailedToParseCrateVersionSemver(_)
+        ));
+        assert!(matches!(
+            error("typenaming:/a;rustc=1.0.0;rustc=1.0.0").kind(),
+            ParseErrorKind::RustcVersionSetTwice
+        ));
+        let error = error("typenaming:/a;size=1");
+        assert!(matches!(error.kind(), ParseErrorKind::UnexpectedData));
+        assert_eq!(14, error.offset());
+        // Percent-escapes may be written in lower case
+        assert_eq!(
+            TypeInfo::from_type_url("typenaming:/a%3C/b%25201%3E").unwrap(),
+            TypeInfo::from_type_url("typenaming:/a%3c/b%25201%3e").unwrap()
+        );
+    }
+}
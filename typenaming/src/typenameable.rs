@@ -1,9 +1,14 @@
-use crate::TypeInfo;
+use crate::{TypeInfo, TypeShape};
 
 /// This trait allows to extract some basic information about the type
 pub trait TypeNameable {
     /// Fetch name of type and related information
     fn type_info() -> TypeInfo;
+    /// Fetch fields or variants of the type - if recorded
+    /// The derive macro records them, if the argument '#[typenameable(fields)]' is given
+    fn type_shape() -> Option<TypeShape> {
+        None
+    }
 }
 
 /// This trait allows to extract some basic information about the type
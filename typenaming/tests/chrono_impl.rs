@@ -0,0 +1,37 @@
+#![cfg(feature = "chrono")]
+
+#[test]
+fn date_time() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(chrono::DateTime::<chrono::Utc>::type_info());
+    assert_eq!("DateTime", type_name.type_name());
+    assert_eq!(Some("chrono"), type_name.crate_name().as_deref());
+    assert_eq!(Some("chrono"), type_name.crate_module().as_deref());
+    let version = type_name.crate_version().clone().unwrap();
+    assert_eq!((0, 4), (version.major, version.minor));
+    assert_eq!(
+        &Some(rustc_version::version().unwrap()),
+        type_name.rustc_version()
+    );
+    assert_eq!(1, type_name.generics().len());
+    assert_eq!("Utc", type_name.generics()[0].type_name());
+    assert_eq!(
+        type_name.crate_version(),
+        type_name.generics()[0].crate_version()
+    );
+}
+
+#[test]
+fn naive() {
+    use ::typenaming::TypeNameable;
+    assert_eq!("NaiveDate", chrono::NaiveDate::type_info().type_name());
+    assert_eq!(
+        "NaiveDateTime",
+        <&chrono::NaiveDateTime>::type_info().type_name()
+    );
+    assert_eq!(
+        "FixedOffset",
+        chrono::DateTime::<chrono::FixedOffset>::type_info().generics()[0].type_name()
+    );
+    assert_eq!("TimeDelta", chrono::Duration::type_info().type_name());
+}
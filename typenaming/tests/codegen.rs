@@ -0,0 +1,124 @@
+#![cfg(feature = "codegen")]
+use proc_macro2::TokenStream;
+use typenaming::{TypeInfo, TypeNameable};
+
+/// Tokens are compared without whitespace, since the spacing of punctuation depends on how the tokens are created
+fn without_whitespace(tokens: TokenStream) -> String {
+    tokens.to_string().split_whitespace().collect()
+}
+
+fn tokens(s: &str) -> String {
+    without_whitespace(s.parse().unwrap())
+}
+
+fn generated(info: &TypeInfo) -> String {
+    without_whitespace(info.to_type_tokens().unwrap())
+}
+
+#[derive(TypeNameable)]
+struct Test;
+
+mod nested {
+    use typenaming::TypeNameable;
+
+    #[derive(TypeNameable)]
+    pub struct Gen<T>(pub T);
+}
+
+#[test]
+fn paths() {
+    assert_eq!(
+        tokens("::std::vec::Vec<::core::primitive::u32>"),
+        generated(&Vec::<u32>::type_info())
+    );
+    assert_eq!(
+        tokens("::codegen::nested::Gen<::codegen::Test>"),
+        generated(&nested::Gen::<Test>::type_info())
+    );
+    let unqualified = TypeInfo::new("Local".to_owned(), None, None, None, None, vec![]);
+    assert_eq!(tokens("Local"), generated(&unqualified));
+}
+
+/// The expected type is compiled as part of this test, hence the generated tokens are a valid path to the same type
+macro_rules! assert_compiles {
+    ($t:ty => $expected:ty) => {
+        assert_eq!(<$t>::type_info(), <$expected>::type_info());
+        assert_eq!(
+            tokens(stringify!($expected)),
+            generated(&<$t>::type_info()),
+            "{}",
+            stringify!($t)
+        );
+    };
+}
+
+#[test]
+fn std_paths_compile() {
+    use std::collections::{BTreeMap, HashMap, VecDeque};
+    use std::num::{NonZeroU8, Wrapping};
+    use std::sync::Arc;
+    assert_compiles!(
+        HashMap<String, Vec<u8>> => ::std::collections::HashMap<
+            ::std::string::String,
+            ::std::vec::Vec<::core::primitive::u8>
+        >
+    );
+    assert_compiles!(
+        BTreeMap<NonZeroU8, VecDeque<Box<u16>>> => ::std::collections::BTreeMap<
+            ::core::num::NonZero<::core::primitive::u8>,
+            ::std::collections::VecDeque<::std::boxed::Box<::core::primitive::u16>>
+        >
+    );
+    assert_compiles!(
+        Arc<Option<Wrapping<i8>>> => ::std::sync::Arc<
+            ::core::option::Option<::core::num::Wrapping<::core::primitive::i8>>
+        >
+    );
+    assert_compiles!(std::io::Error => ::std::io::Error);
+    assert_compiles!(std::ffi::CString => ::std::ffi::CString);
+    assert_compiles!(std::ops::Range<u32> => ::core::ops::Range<::core::primitive::u32>);
+}
+
+#[test]
+fn special_types() {
+    let cases = [
+        ("[u8; 3]", "[::core::primitive::u8; 3]"),
+        ("&[u8]", "[::core::primitive::u8]"),
+        ("*mut str", "*mut ::core::primitive::str"),
+        ("(u8,)", "(::core::primitive::u8,)"),
+        (
+            "(u8, bool)",
+            "(::core::primitive::u8, ::core::primitive::bool)",
+        ),
+        ("()", "()"),
+        ("fn(u8)", "fn(::core::primitive::u8)"),
+        (
+            "unsafe extern \"C\" fn() -> u8",
+            "unsafe extern \"C\" fn() -> ::core::primitive::u8",
+        ),
+        (
+            "dyn core::any::Any + Send + Sync",
+            "dyn ::core::any::Any + ::core::marker::Send + ::core::marker::Sync",
+        ),
+        ("Foo<-3>", "Foo<{ -3 }>"),
+    ];
+    for (rust_type, expected) in cases {
+        let info = TypeInfo::from_rust_type_str(rust_type).unwrap();
+        assert_eq!(tokens(expected), generated(&info), "{rust_type}");
+    }
+}
+
+#[test]
+fn invalid_identifier() {
+    let info = TypeInfo::new("a b".to_owned(), None, None, None, None, vec![]);
+    assert_eq!("a b", info.to_type_tokens().unwrap_err().text());
+    let info = TypeInfo::new(
+        "Test".to_owned(),
+        None,
+        Some("a::1".to_owned()),
+        None,
+        None,
+        vec![],
+    );
+    assert_eq!("1", info.to_type_tokens().unwrap_err().text());
+}
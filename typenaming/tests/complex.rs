@@ -2,6 +2,7 @@
 fn complex() {
     use ::typenaming::TypeNameable;
     #[derive(TypeNameable)]
+    #[allow(dead_code)]
     struct A<'a, 'b, T, S>(&'a T, &'b S);
     let type_name = A::<&'static u32, &'static bool>::type_info();
     assert_eq!("A", type_name.type_name());
@@ -0,0 +1,167 @@
+use typenaming::{DisplayOptions, TypeInfo, TypeNameable, VersionDisplay};
+
+#[test]
+fn short() {
+    assert_eq!("Vec<u32>", Vec::<u32>::type_info().to_string());
+    assert_eq!(
+        "Result<String, ParseIntError>",
+        Result::<String, std::num::ParseIntError>::type_info().to_string()
+    );
+    assert_eq!("str", <&str>::type_info().to_string());
+    assert_eq!("()", <()>::type_info().to_string());
+}
+
+#[test]
+fn qualified() {
+    assert_eq!(
+        "alloc::vec::Vec<u32>",
+        format!("{:#}", Vec::<u32>::type_info())
+    );
+    assert_eq!(
+        "alloc::boxed::Box<[alloc::string::String; 3]>",
+        format!("{:#}", Box::<[String; 3]>::type_info())
+    );
+}
+
+#[test]
+fn special_types() {
+    let display = |s: &str| TypeInfo::from_rust_type_str(s).unwrap().to_string();
+    assert_eq!("[u8; 4]", display("[u8; 4]"));
+    assert_eq!("[u8]", display("&[u8]"));
+    assert_eq!("(u8, bool)", display("(u8, bool)"));
+    assert_eq!("(u8,)", display("(u8,)"));
+    assert_eq!("*const u8", display("*const u8"));
+    assert_eq!("fn(u32, bool) -> u8", display("fn(u32, bool) -> u8"));
+    assert_eq!(
+        "unsafe extern \"C\" fn()",
+        display("unsafe extern \"C\" fn()")
+    );
+    assert_eq!(
+        "dyn Iterator<u8> + Send",
+        display("dyn core::iter::Iterator<Item = u8> + core::marker::Send")
+    );
+    assert_eq!(
+        "dyn core::iter::Iterator<u8> + Send",
+        format!(
+            "{:#}",
+            TypeInfo::from_rust_type_str("dyn core::iter::Iterator<Item = u8> + Send").unwrap()
+        )
+    );
+}
+
+#[test]
+fn versions() {
+    let version = typenaming::Version::new(1, 2, 3);
+    let info = TypeInfo::new(
+        "Wrapper".to_owned(),
+        Some("wrapping".to_owned()),
+        Some("wrapping::types".to_owned()),
+        Some(version.clone()),
+        Some(typenaming::Version::new(1, 70, 0)),
+        vec![TypeInfo::new(
+            "Inner".to_owned(),
+            None,
+            None,
+            Some(version),
+            None,
+            vec![],
+        )],
+    );
+    let top_level = DisplayOptions {
+        versions: VersionDisplay::TopLevel,
+        ..Default::default()
+    };
+    assert_eq!(
+        "Wrapper<Inner> (1.2.3, rustc 1.70.0)",
+        info.display_with(top_level).to_string()
+    );
+    let all = DisplayOptions {
+        qualified: true,
+        versions: VersionDisplay::All,
+    };
+    assert_eq!(
+        "wrapping::types::Wrapper<Inner (1.2.3)> (1.2.3, rustc 1.70.0)",
+        info.display_with(all).to_string()
+    );
+    assert_eq!(
+        "Wrapper<Inner>",
+        info.display_with(Default::default()).to_string()
+    );
+}
+
+fn node(
+    name: &str,
+    krate: Option<&str>,
+    version: Option<u64>,
+    generics: Vec<TypeInfo>,
+) -> TypeInfo {
+    TypeInfo::new(
+        name.to_owned(),
+        krate.map(str::to_owned),
+        krate.map(|x| format!("{x}::types")),
+        version.map(|x| typenaming::Version::new(x, 0, 0)),
+        Some(typenaming::Version::new(1, 70, 0)),
+        generics,
+    )
+}
+
+fn tree_example() -> TypeInfo {
+    let test = || node("Test", Some("testing"), Some(1), vec![]);
+    node(
+        "Gen",
+        Some("generic2"),
+        Some(2),
+        vec![
+            test(),
+            node("Gen", Some("generic"), None, vec![test()]),
+            test(),
+        ],
+    )
+}
+
+#[test]
+fn tree() {
+    let expected = [
+        "Gen         generic2  generic2::types  2.0.0  rustc 1.70.0",
+        "├─ Test     testing   testing::types   1.0.0  rustc 1.70.0",
+        "├─ Gen      generic   generic::types   -      rustc 1.70.0",
+        "│  └─ Test  testing   testing::types   1.0.0  rustc 1.70.0",
+        "└─ Test     testing   testing::types   1.0.0  rustc 1.70.0",
+    ];
+    assert_eq!(
+        expected.join("\n"),
+        tree_example().display_tree().to_string()
+    );
+    let unit = TypeInfo::new("()".to_owned(), None, None, None, None, vec![]);
+    assert_eq!("()  -  -  -  -", unit.display_tree().to_string());
+}
+
+#[test]
+fn tree_with_reference() {
+    let reference = tree_example();
+    let info = node(
+        "Gen",
+        Some("generic2"),
+        Some(2),
+        vec![
+            node("Test", Some("testing"), Some(3), vec![]),
+            node("Gen", Some("generic"), None, vec![]),
+        ],
+    );
+    let expected = [
+        "  Gen                   generic2  generic2::types  2.0.0  rustc 1.70.0",
+        "* ├─ Test               testing   testing::types   3.0.0  rustc 1.70.0",
+        "  ├─ Gen                generic   generic::types   -      rustc 1.70.0",
+        "* │  └─ (missing) Test  testing   testing::types   1.0.0  rustc 1.70.0",
+        "* └─ (missing) Test     testing   testing::types   1.0.0  rustc 1.70.0",
+    ];
+    assert_eq!(
+        expected.join("\n"),
+        info.display_tree().with_reference(&reference).to_string()
+    );
+    let same = reference
+        .display_tree()
+        .with_reference(&reference)
+        .to_string();
+    assert!(same.lines().all(|line| line.starts_with("  ")));
+}
@@ -0,0 +1,108 @@
+use typenaming::{TypeInfo, TypeNameable, Version};
+
+fn node(name: &str, krate: Option<&str>, version: u64, generics: Vec<TypeInfo>) -> TypeInfo {
+    TypeInfo::new(
+        name.to_owned(),
+        krate.map(str::to_owned),
+        krate.map(|x| format!("{x}::types")),
+        krate.map(|_| Version::new(version, 0, 0)),
+        None,
+        generics,
+    )
+}
+
+#[test]
+fn render() {
+    let test = |version| node("Test", Some("testing"), version, vec![]);
+    let first = node(
+        "Gen",
+        Some("generic"),
+        1,
+        vec![test(1), node("Gen", Some("generic"), 1, vec![test(1)])],
+    );
+    let second = node(
+        "Gen",
+        Some("generic"),
+        1,
+        vec![test(2), TypeInfo::new_const_argument("\"a\"".to_owned())],
+    );
+    let expected = r#"digraph types {
+    node [shape=box];
+    subgraph cluster_0 {
+        label="generic 1.0.0";
+        n0 [label="Gen<Test, Gen<Test>>", style=bold];
+        n2 [label="Gen<Test>"];
+        n3 [label="Gen<Test, \"a\">", style=bold];
+    }
+    subgraph cluster_1 {
+        label="testing 1.0.0";
+        n1 [label="Test"];
+    }
+    subgraph cluster_2 {
+        label="testing 2.0.0";
+        n4 [label="Test"];
+    }
+    n5 [label="\"a\""];
+    n0 -> n1;
+    n0 -> n2;
+    n2 -> n1;
+    n3 -> n4;
+    n3 -> n5;
+}
+"#;
+    assert_eq!(
+        expected,
+        typenaming::dot::render(&[first.clone(), second, first])
+    );
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct User {
+    name: String,
+    role: Role,
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+enum Role {
+    Guest,
+    Admin { level: u8 },
+    Member(Vec<u8>),
+}
+
+#[test]
+fn render_with_fields() {
+    let rendered = typenaming::dot::render_with_fields(&[(User::type_info(), User::type_shape())]);
+    let lines: Vec<&str> = rendered.lines().collect();
+    let node = |label: &str| {
+        let line = lines
+            .iter()
+            .find(|line| line.contains(&format!("[label=\"{label}\"")))
+            .unwrap();
+        line.split_whitespace().next().unwrap().to_owned()
+    };
+    let (user, role, string, u8, vec) = (
+        node("User"),
+        node("Role"),
+        node("String"),
+        node("u8"),
+        node("Vec<u8>"),
+    );
+    for edge in [
+        format!("{user} -> {string} [label=\"name\", style=dashed];"),
+        format!("{user} -> {role} [label=\"role\", style=dashed];"),
+        format!("{role} -> {u8} [label=\"Admin.level\", style=dashed];"),
+        format!("{role} -> {vec} [label=\"Member.0\", style=dashed];"),
+        format!("{vec} -> {u8};"),
+    ] {
+        assert!(
+            lines.contains(&format!("    {edge}").as_str()),
+            "{rendered}"
+        );
+    }
+    assert_eq!(5, rendered.matches(" -> ").count(), "{rendered}");
+    assert!(rendered.contains(&format!("{user} [label=\"User\", style=bold];")));
+}
@@ -0,0 +1,125 @@
+#[test]
+#[cfg(feature = "uuid")]
+fn uuid() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(uuid::Uuid::type_info());
+    assert_eq!("Uuid", type_name.type_name());
+    assert_eq!(Some("uuid"), type_name.crate_name().as_deref());
+    assert_eq!(Some("uuid"), type_name.crate_module().as_deref());
+    assert_eq!(1, type_name.crate_version().as_ref().unwrap().major);
+}
+
+#[test]
+#[cfg(feature = "url")]
+fn url() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(url::Url::type_info());
+    assert_eq!("Url", type_name.type_name());
+    assert_eq!(2, type_name.crate_version().as_ref().unwrap().major);
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn bytes() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(bytes::Bytes::type_info());
+    assert_eq!("Bytes", type_name.type_name());
+    assert_eq!(Some("bytes"), type_name.crate_module().as_deref());
+    assert_eq!("BytesMut", bytes::BytesMut::type_info().type_name());
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn serde_json() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(serde_json::Value::type_info());
+    assert_eq!("Value", type_name.type_name());
+    assert_eq!(Some("serde_json"), type_name.crate_name().as_deref());
+    assert!(type_name.crate_version().is_some());
+}
+
+#[test]
+#[cfg(feature = "indexmap")]
+fn indexmap() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(indexmap::IndexMap::<String, u32>::type_info());
+    assert_eq!("IndexMap", type_name.type_name());
+    assert_eq!(Some("indexmap"), type_name.crate_module().as_deref());
+    assert_eq!(3, type_name.generics().len());
+    assert_eq!("String", type_name.generics()[0].type_name());
+    assert_eq!("u32", type_name.generics()[1].type_name());
+    assert_eq!("RandomState", type_name.generics()[2].type_name());
+    let type_name = dbg!(indexmap::IndexSet::<u8>::type_info());
+    assert_eq!("IndexSet", type_name.type_name());
+    assert_eq!(2, type_name.generics().len());
+}
+
+#[test]
+#[cfg(feature = "smallvec")]
+fn smallvec() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(smallvec::SmallVec::<[u16; 4]>::type_info());
+    assert_eq!("SmallVec", type_name.type_name());
+    assert_eq!(1, type_name.generics().len());
+    let array = &type_name.generics()[0];
+    assert_eq!("array", array.type_name());
+    assert_eq!("u16", array.generics()[0].type_name());
+    assert_eq!("4", array.generics()[1].type_name());
+    assert!(array.generics()[1].is_const_argument());
+    assert_ne!(type_name, smallvec::SmallVec::<[u16; 8]>::type_info());
+}
+
+#[test]
+#[cfg(feature = "rust_decimal")]
+fn rust_decimal() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(rust_decimal::Decimal::type_info());
+    assert_eq!("Decimal", type_name.type_name());
+    assert_eq!(Some("rust_decimal"), type_name.crate_name().as_deref());
+}
+
+/// The recorded versions are the resolved versions, which are listed in the lockfile of this workspace
+#[test]
+fn versions_match_lockfile() {
+    let lockfile = concat!(env!("CARGO_MANIFEST_DIR"), "/../Cargo.lock");
+    let lockfile = std::fs::read_to_string(lockfile).unwrap();
+    let packages: Vec<(&str, &str)> = lockfile
+        .split("[[package]]")
+        .filter_map(|package| {
+            let value = |key: &str| {
+                package
+                    .lines()
+                    .find_map(|line| line.strip_prefix(key))
+                    .map(|x| x.trim_matches('"'))
+            };
+            Some((value("name = ")?, value("version = ")?))
+        })
+        .collect();
+    for name in [
+        "bytes",
+        "chrono",
+        "half",
+        "indexmap",
+        "nalgebra",
+        "ndarray",
+        "num-complex",
+        "rust_decimal",
+        "semver",
+        "serde_json",
+        "smallvec",
+        "time",
+        "url",
+        "uuid",
+    ] {
+        let Some(version) =
+            typenaming::dependency_impl::dependency_version(&name.replace('-', "_"))
+        else {
+            continue;
+        };
+        let version = version.to_string();
+        assert!(
+            packages.contains(&(name, version.as_str())),
+            "{name} {version}"
+        );
+    }
+}
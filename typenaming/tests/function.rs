@@ -0,0 +1,46 @@
+#[test]
+fn function_pointer() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(<fn(u32, String) -> bool>::type_info());
+    assert_eq!("fn", type_name.type_name());
+    assert_eq!(Some("core"), type_name.crate_name().as_deref());
+    assert!(type_name.is_function_pointer());
+    let inputs = type_name.function_inputs().unwrap();
+    assert_eq!(2, inputs.len());
+    assert_eq!("u32", inputs[0].type_name());
+    assert_eq!("String", inputs[1].type_name());
+    assert_eq!("bool", type_name.function_output().unwrap().type_name());
+}
+
+#[test]
+fn function_pointer_without_arguments() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(<fn()>::type_info());
+    assert_eq!("fn", type_name.type_name());
+    assert_eq!(Some(&[][..]), type_name.function_inputs());
+    assert_eq!("()", type_name.function_output().unwrap().type_name());
+}
+
+#[test]
+fn function_pointer_variants() {
+    use ::typenaming::TypeNameable;
+    type Callback = unsafe extern "C" fn(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8) -> u8;
+    let type_name = dbg!(Callback::type_info());
+    assert_eq!("unsafe extern \"C\" fn", type_name.type_name());
+    assert_eq!(12, type_name.function_inputs().unwrap().len());
+    assert_eq!("unsafe fn", <unsafe fn(bool)>::type_info().type_name());
+    assert_eq!(
+        "extern \"C\" fn",
+        <extern "C" fn(bool)>::type_info().type_name()
+    );
+    assert_ne!(<fn(bool)>::type_info(), <unsafe fn(bool)>::type_info());
+}
+
+#[test]
+fn not_a_function_pointer() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(Vec::<u8>::type_info());
+    assert!(!type_name.is_function_pointer());
+    assert_eq!(None, type_name.function_inputs());
+    assert_eq!(None, type_name.function_output());
+}
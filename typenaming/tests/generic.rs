@@ -2,6 +2,7 @@
 fn simple() {
     use ::typenaming::TypeNameable;
     #[derive(TypeNameable)]
+    #[allow(dead_code)]
     struct A<T>(T);
     let type_name = dbg!(A::<u32>::type_info());
     assert_eq!("A", type_name.type_name());
@@ -18,6 +19,7 @@ fn simple() {
 fn two() {
     use ::typenaming::TypeNameable;
     #[derive(TypeNameable)]
+    #[allow(dead_code)]
     struct A<T, U>(T, U);
     let type_name = dbg!(A::<u32, bool>::type_info());
     assert_eq!("A", type_name.type_name());
@@ -0,0 +1,154 @@
+#![cfg(feature = "json-schema")]
+
+use serde_json::json;
+use typenaming::TypeNameable;
+
+#[derive(TypeNameable)]
+#[typenameable(fields, crate_version = "1.2.3")]
+#[allow(dead_code)]
+struct Config<T> {
+    name: String,
+    port: u16,
+    limits: Option<[u32; 2]>,
+    servers: Vec<T>,
+    next: Option<Box<Config<T>>>,
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields, crate_version = "1.2.3")]
+#[allow(dead_code)]
+enum Server {
+    Local,
+    Remote(String),
+    Pair(String, u16),
+    Named { host: String },
+}
+
+#[test]
+fn schema() {
+    let schema = typenaming::json_schema::schema::<Config<Server>>();
+    let expected = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": "typenaming:typenaming@1.2.3/json_schema/Config%3Ctypenaming@1.2.3/json_schema/Server%3E",
+        "title": Config::<Server>::type_info().to_string(),
+        "type": "object",
+        "properties": {
+            "name": { "type": "string" },
+            "port": { "type": "integer", "minimum": 0, "maximum": 65535 },
+            "limits": {
+                "anyOf": [
+                    {
+                        "type": "array",
+                        "items": { "type": "integer", "minimum": 0, "maximum": 4294967295u32 },
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    { "type": "null" },
+                ],
+            },
+            "servers": { "type": "array", "items": { "$ref": "#/$defs/Server" } },
+            "next": { "anyOf": [{ "$ref": "#" }, { "type": "null" }] },
+        },
+        "required": ["name", "port", "servers"],
+        "$defs": {
+            "Server": {
+                "title": Server::type_info().to_string(),
+                "oneOf": [
+                    { "const": "Local" },
+                    {
+                        "type": "object",
+                        "properties": { "Remote": { "type": "string" } },
+                        "required": ["Remote"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Pair": {
+                                "type": "array",
+                                "prefixItems": [
+                                    { "type": "string" },
+                                    { "type": "integer", "minimum": 0, "maximum": 65535 },
+                                ],
+                                "items": false,
+                                "minItems": 2,
+                            },
+                        },
+                        "required": ["Pair"],
+                        "additionalProperties": false,
+                    },
+                    {
+                        "type": "object",
+                        "properties": {
+                            "Named": {
+                                "type": "object",
+                                "properties": { "host": { "type": "string" } },
+                                "required": ["host"],
+                            },
+                        },
+                        "required": ["Named"],
+                        "additionalProperties": false,
+                    },
+                ],
+            },
+        },
+    });
+    assert_eq!(expected, schema);
+}
+
+#[test]
+fn without_fields() {
+    let schema = typenaming::json_schema::schema::<std::collections::BTreeMap<String, bool>>();
+    assert_eq!(json!("object"), schema["type"]);
+    assert_eq!(json!({ "type": "boolean" }), schema["additionalProperties"]);
+    assert_eq!(None, schema.get("$defs"));
+}
+
+mod api {
+    use typenaming::TypeNameable;
+
+    #[derive(TypeNameable)]
+    #[typenameable(fields)]
+    #[allow(dead_code)]
+    pub struct Error {
+        pub code: u16,
+    }
+}
+
+mod db {
+    use typenaming::TypeNameable;
+
+    #[derive(TypeNameable)]
+    #[typenameable(fields)]
+    #[allow(dead_code)]
+    pub struct Error(pub String);
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct Failure {
+    api: api::Error,
+    db: db::Error,
+}
+
+#[test]
+fn colliding_names() {
+    let schema = typenaming::json_schema::schema::<Failure>();
+    assert_eq!(
+        json!({ "$ref": "#/$defs/json_schema_api_Error" }),
+        schema["properties"]["api"]
+    );
+    assert_eq!(
+        json!({ "$ref": "#/$defs/json_schema_db_Error" }),
+        schema["properties"]["db"]
+    );
+    assert_eq!(
+        json!("object"),
+        schema["$defs"]["json_schema_api_Error"]["type"]
+    );
+    assert_eq!(
+        json!("string"),
+        schema["$defs"]["json_schema_db_Error"]["type"]
+    );
+}
@@ -2,6 +2,7 @@
 fn simple() {
     use ::typenaming::TypeNameable;
     #[derive(TypeNameable)]
+    #[allow(dead_code)]
     struct A<'a, T>(&'a T);
     let type_name = dbg!(A::<&'static u32>::type_info());
     assert_eq!("A", type_name.type_name());
@@ -0,0 +1,54 @@
+#[test]
+#[cfg(feature = "nalgebra")]
+fn nalgebra() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(nalgebra::Matrix3::<f64>::type_info());
+    assert_eq!("SMatrix", type_name.type_name());
+    assert_eq!(Some("nalgebra"), type_name.crate_name().as_deref());
+    assert!(type_name.crate_version().is_some());
+    assert_eq!(3, type_name.generics().len());
+    assert_eq!("f64", type_name.generics()[0].type_name());
+    assert_eq!("3", type_name.generics()[1].type_name());
+    assert!(type_name.generics()[1].is_const_argument());
+    assert_eq!("3", type_name.generics()[2].type_name());
+    assert_ne!(type_name, nalgebra::Matrix4::<f32>::type_info());
+    assert_ne!(
+        nalgebra::SMatrix::<f64, 2, 3>::type_info(),
+        nalgebra::SMatrix::<f64, 3, 2>::type_info()
+    );
+}
+
+#[test]
+#[cfg(feature = "ndarray")]
+fn ndarray() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(ndarray::Array2::<f32>::type_info());
+    assert_eq!("Array", type_name.type_name());
+    assert_eq!(Some("ndarray"), type_name.crate_name().as_deref());
+    assert_eq!(2, type_name.generics().len());
+    assert_eq!("f32", type_name.generics()[0].type_name());
+    assert_eq!("2", type_name.generics()[1].type_name());
+    let type_name = dbg!(ndarray::ArrayD::<f32>::type_info());
+    assert_eq!(1, type_name.generics().len());
+}
+
+#[test]
+#[cfg(feature = "num-complex")]
+fn num_complex() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(num_complex::Complex::<f64>::type_info());
+    assert_eq!("Complex", type_name.type_name());
+    assert_eq!(Some("num_complex"), type_name.crate_name().as_deref());
+    assert!(type_name.crate_version().is_some());
+    assert_eq!("f64", type_name.generics()[0].type_name());
+}
+
+#[test]
+#[cfg(feature = "half")]
+fn half() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(half::f16::type_info());
+    assert_eq!("f16", type_name.type_name());
+    assert_eq!(Some("half"), type_name.crate_module().as_deref());
+    assert_ne!(type_name, half::bf16::type_info());
+}
@@ -0,0 +1,114 @@
+use typenaming::{TypeInfo, TypeNameable};
+
+fn from_type_name<T: ?Sized>() -> TypeInfo {
+    let type_name = std::any::type_name::<T>();
+    TypeInfo::from_rust_type_str(dbg!(type_name)).unwrap()
+}
+
+#[test]
+fn matches_derived_std_types() {
+    assert_eq!(Vec::<u8>::type_info(), from_type_name::<Vec<u8>>());
+    assert_eq!(
+        Result::<String, std::num::ParseIntError>::type_info(),
+        from_type_name::<Result<String, std::num::ParseIntError>>()
+    );
+    assert_eq!(
+        Box::<[Vec<u32>; 4]>::type_info(),
+        from_type_name::<Box<[Vec<u32>; 4]>>()
+    );
+    assert_eq!(<&str>::type_info(), from_type_name::<&str>());
+    assert_eq!(
+        <fn(u32, String) -> bool>::type_info(),
+        from_type_name::<fn(u32, String) -> bool>()
+    );
+    assert_eq!(
+        <unsafe extern "C" fn()>::type_info(),
+        from_type_name::<unsafe extern "C" fn()>()
+    );
+    assert_eq!(<()>::type_info(), from_type_name::<()>());
+    // 'std::any::type_name' shows private modules, e.g. 'std::collections::hash::map'
+    assert_eq!(
+        std::collections::HashMap::<std::num::NonZeroU8, std::collections::VecDeque<std::io::Error>>::type_info(),
+        from_type_name::<std::collections::HashMap<std::num::NonZeroU8, std::collections::VecDeque<std::io::Error>>>()
+    );
+}
+
+#[test]
+fn path() {
+    let info = TypeInfo::from_rust_type_str("alloc::vec::Vec<core::option::Option<u8>>").unwrap();
+    assert_eq!("Vec", info.type_name());
+    assert_eq!(Some("alloc"), info.crate_name().as_deref());
+    assert_eq!(Some("alloc::vec"), info.crate_module().as_deref());
+    let option = &info.generics()[0];
+    assert_eq!("Option", option.type_name());
+    assert_eq!(Some("core::option"), option.crate_module().as_deref());
+    assert_eq!(
+        Some("core::primitive"),
+        option.generics()[0].crate_module().as_deref()
+    );
+
+    let info = TypeInfo::from_rust_type_str("my_crate::inner::Wrapper<'static, T, 3>").unwrap();
+    assert_eq!(Some("my_crate"), info.crate_name().as_deref());
+    assert_eq!(&None, info.crate_version());
+    assert_eq!(2, info.generics().len());
+    assert_eq!(None, info.generics()[0].crate_name().as_deref());
+    assert!(info.generics()[1].is_const_argument());
+}
+
+#[test]
+fn tuples_slices_and_pointers() {
+    let info = TypeInfo::from_rust_type_str("(u8, &[alloc::string::String], *const i32)").unwrap();
+    assert_eq!("tuple", info.type_name());
+    assert_eq!(3, info.generics().len());
+    assert_eq!("slice", info.generics()[1].type_name());
+    assert_eq!("String", info.generics()[1].generics()[0].type_name());
+    assert_eq!("*const", info.generics()[2].type_name());
+    assert_eq!(
+        "tuple",
+        TypeInfo::from_rust_type_str("(u8,)").unwrap().type_name()
+    );
+    assert_eq!(
+        "u8",
+        TypeInfo::from_rust_type_str("(u8)").unwrap().type_name()
+    );
+}
+
+#[test]
+fn trait_objects() {
+    let info = TypeInfo::from_rust_type_str(std::any::type_name::<
+        Box<dyn std::fmt::Debug + Send + Sync>,
+    >())
+    .unwrap();
+    let inner = &info.generics()[0];
+    assert_eq!("dyn Debug + Send + Sync", inner.type_name());
+    assert_eq!(Some("core::fmt"), inner.crate_module().as_deref());
+
+    let info = TypeInfo::from_rust_type_str(
+        "&dyn for<'a> core::ops::function::Fn(&'a u8) -> bool + 'static",
+    )
+    .unwrap();
+    assert_eq!("dyn Fn", info.type_name());
+    assert_eq!(2, info.generics().len());
+
+    let info = TypeInfo::from_rust_type_str("dyn core::iter::Iterator<Item = u8>").unwrap();
+    assert_eq!("dyn Iterator", info.type_name());
+    assert_eq!("u8", info.generics()[0].type_name());
+}
+
+#[test]
+fn invalid() {
+    let error = TypeInfo::from_rust_type_str("Vec<u8").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        typenaming::ParseErrorKind::MissingGenericEnd
+    ));
+    assert_eq!(6, error.offset());
+    assert!(TypeInfo::from_rust_type_str("u8 u16").is_err());
+    assert!(TypeInfo::from_rust_type_str("").is_err());
+    assert!(TypeInfo::from_rust_type_str("[u8; ]").is_err());
+    let nested = "A<".repeat(1000) + &">".repeat(1000);
+    assert!(matches!(
+        TypeInfo::from_rust_type_str(&nested).unwrap_err().kind(),
+        typenaming::ParseErrorKind::NestingTooDeep
+    ));
+}
@@ -0,0 +1,47 @@
+#[test]
+fn type_info() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(typenaming::TypeInfo::type_info());
+    assert_eq!("TypeInfo", type_name.type_name());
+    assert_eq!(Some("typenaming"), type_name.crate_name().as_deref());
+    assert_eq!(
+        Some("typenaming::type_info"),
+        type_name.crate_module().as_deref()
+    );
+    assert_eq!(
+        &Some(typenaming::Version::parse(env!("CARGO_PKG_VERSION")).unwrap()),
+        type_name.crate_version()
+    );
+}
+
+#[test]
+fn version() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(typenaming::Version::type_info());
+    assert_eq!("Version", type_name.type_name());
+    assert_eq!(Some("semver"), type_name.crate_name().as_deref());
+    assert_eq!(1, type_name.crate_version().as_ref().unwrap().major);
+}
+
+#[test]
+fn parse_error() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(typenaming::ParseError::type_info());
+    assert_eq!("ParseError", type_name.type_name());
+    assert_eq!(Some("typenaming"), type_name.crate_name().as_deref());
+}
+
+#[test]
+fn nested() {
+    use ::typenaming::TypeNameable;
+    #[derive(TypeNameable)]
+    #[allow(dead_code)]
+    struct Envelope<T> {
+        info: typenaming::TypeInfo,
+        version: typenaming::Version,
+        payload: T,
+    }
+    let type_name = dbg!(Envelope::<typenaming::TypeInfo>::type_info());
+    assert_eq!("Envelope", type_name.type_name());
+    assert_eq!("TypeInfo", type_name.generics()[0].type_name());
+}
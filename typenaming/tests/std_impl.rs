@@ -0,0 +1,160 @@
+#[test]
+fn primitive() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(u32::type_info());
+    assert_eq!("u32", type_name.type_name());
+    assert_eq!(Some("core"), type_name.crate_name().as_deref());
+    assert_eq!(Some("core::primitive"), type_name.crate_module().as_deref());
+    assert_eq!(0, type_name.generics().len());
+}
+
+#[test]
+fn alloc() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(Vec::<String>::type_info());
+    assert_eq!("Vec", type_name.type_name());
+    assert_eq!(Some("alloc"), type_name.crate_name().as_deref());
+    assert_eq!(Some("alloc::vec"), type_name.crate_module().as_deref());
+    assert_eq!(1, type_name.generics().len());
+    assert_eq!("String", type_name.generics()[0].type_name());
+    assert_eq!(
+        Some("alloc"),
+        type_name.generics()[0].crate_name().as_deref()
+    );
+    assert_eq!(
+        Some("alloc::string"),
+        type_name.generics()[0].crate_module().as_deref()
+    );
+}
+
+#[test]
+#[cfg(feature = "std-versions")]
+fn versions() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(Result::<u8, String>::type_info());
+    let rustc_version = Some(rustc_version::version().unwrap());
+    assert_eq!(&rustc_version, type_name.crate_version());
+    assert_eq!(&rustc_version, type_name.rustc_version());
+    assert_eq!(&rustc_version, type_name.generics()[0].crate_version());
+    assert_eq!(&rustc_version, type_name.generics()[1].rustc_version());
+}
+
+#[test]
+#[cfg(not(feature = "std-versions"))]
+fn versions() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(Result::<u8, String>::type_info());
+    assert_eq!(&None, type_name.crate_version());
+    assert_eq!(&None, type_name.rustc_version());
+    assert_eq!(&None, type_name.generics()[0].crate_version());
+    assert_eq!(&None, type_name.generics()[1].rustc_version());
+}
+
+#[test]
+fn leaf_types() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(std::time::Duration::type_info());
+    assert_eq!("Duration", type_name.type_name());
+    assert_eq!(Some("core"), type_name.crate_name().as_deref());
+    assert_eq!(Some("core::time"), type_name.crate_module().as_deref());
+
+    let type_name = dbg!(std::path::PathBuf::type_info());
+    assert_eq!("PathBuf", type_name.type_name());
+    assert_eq!(Some("std"), type_name.crate_name().as_deref());
+    assert_eq!(Some("std::path"), type_name.crate_module().as_deref());
+
+    let type_name = dbg!(<&std::path::Path>::type_info());
+    assert_eq!("Path", type_name.type_name());
+
+    let type_name = dbg!(std::sync::Arc::<std::path::Path>::type_info());
+    assert_eq!("Arc", type_name.type_name());
+    assert_eq!("Path", type_name.generics()[0].type_name());
+    assert_eq!(
+        Some("std::path"),
+        type_name.generics()[0].crate_module().as_deref()
+    );
+
+    let type_name = dbg!(Box::<str>::type_info());
+    assert_eq!("Box", type_name.type_name());
+    assert_eq!("str", type_name.generics()[0].type_name());
+    assert_eq!(
+        Some("core::primitive"),
+        type_name.generics()[0].crate_module().as_deref()
+    );
+
+    let type_name = dbg!(std::io::Error::type_info());
+    assert_eq!("Error", type_name.type_name());
+    assert_eq!(Some("std::io"), type_name.crate_module().as_deref());
+
+    let type_name = dbg!(std::net::SocketAddr::type_info());
+    assert_eq!("SocketAddr", type_name.type_name());
+    assert_eq!(Some("core::net"), type_name.crate_module().as_deref());
+}
+
+#[test]
+fn generic_leaf_types() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(std::num::NonZeroU16::type_info());
+    assert_eq!("NonZero", type_name.type_name());
+    assert_eq!(Some("core::num"), type_name.crate_module().as_deref());
+    assert_eq!(1, type_name.generics().len());
+    assert_eq!("u16", type_name.generics()[0].type_name());
+
+    let type_name = dbg!(std::ops::RangeInclusive::<std::num::Wrapping<i64>>::type_info());
+    assert_eq!("RangeInclusive", type_name.type_name());
+    assert_eq!(Some("core::ops"), type_name.crate_module().as_deref());
+    assert_eq!("Wrapping", type_name.generics()[0].type_name());
+    assert_eq!("i64", type_name.generics()[0].generics()[0].type_name());
+
+    let type_name = dbg!(std::marker::PhantomData::<std::sync::atomic::AtomicBool>::type_info());
+    assert_eq!("PhantomData", type_name.type_name());
+    assert_eq!("AtomicBool", type_name.generics()[0].type_name());
+    assert_eq!(
+        Some("core::sync::atomic"),
+        type_name.generics()[0].crate_module().as_deref()
+    );
+}
+
+#[test]
+fn collections() {
+    use ::typenaming::TypeNameable;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+    let type_name = dbg!(HashMap::<String, VecDeque<Option<u8>>>::type_info());
+    assert_eq!("HashMap", type_name.type_name());
+    assert_eq!(Some("std"), type_name.crate_name().as_deref());
+    assert_eq!(
+        Some("std::collections"),
+        type_name.crate_module().as_deref()
+    );
+    assert_eq!(2, type_name.generics().len());
+    let deque = &type_name.generics()[1];
+    assert_eq!("VecDeque", deque.type_name());
+    assert_eq!(Some("alloc"), deque.crate_name().as_deref());
+    assert_eq!(Some("alloc::collections"), deque.crate_module().as_deref());
+    let option = &deque.generics()[0];
+    assert_eq!("Option", option.type_name());
+    assert_eq!(Some("core::option"), option.crate_module().as_deref());
+
+    for (type_name, module) in [
+        (BTreeMap::<u8, u8>::type_info(), "alloc::collections"),
+        (BTreeSet::<u8>::type_info(), "alloc::collections"),
+        (HashSet::<u8>::type_info(), "std::collections"),
+    ] {
+        assert_eq!(Some(module), type_name.crate_module().as_deref());
+    }
+}
+
+#[test]
+fn array() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(<[bool; 3]>::type_info());
+    assert_eq!("array", type_name.type_name());
+    assert_eq!(Some("core"), type_name.crate_name().as_deref());
+    assert_eq!(2, type_name.generics().len());
+    assert_eq!("bool", type_name.generics()[0].type_name());
+    assert!(!type_name.generics()[0].is_const_argument());
+    assert_eq!("3", type_name.generics()[1].type_name());
+    assert!(type_name.generics()[1].is_const_argument());
+    assert_eq!(&None, type_name.generics()[1].crate_name());
+    assert_ne!(type_name, <[bool; 4]>::type_info());
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "time")]
+
+#[test]
+fn offset_date_time() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(time::OffsetDateTime::type_info());
+    assert_eq!("OffsetDateTime", type_name.type_name());
+    assert_eq!(Some("time"), type_name.crate_name().as_deref());
+    assert_eq!(Some("time"), type_name.crate_module().as_deref());
+    let version = type_name.crate_version().clone().unwrap();
+    assert_eq!((0, 3), (version.major, version.minor));
+    assert_eq!(0, type_name.generics().len());
+}
+
+#[test]
+fn date() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(time::Date::type_info());
+    assert_eq!("Date", type_name.type_name());
+    assert_eq!(Some("time"), type_name.crate_module().as_deref());
+    assert_ne!(
+        time::PrimitiveDateTime::type_info(),
+        time::OffsetDateTime::type_info()
+    );
+}
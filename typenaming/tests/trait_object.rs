@@ -0,0 +1,71 @@
+#[typenaming::nameable_trait]
+pub trait Plugin {
+    fn name(&self) -> String;
+}
+
+#[typenaming::nameable_trait]
+pub trait Handler<Req> {
+    fn handle(&self, request: Req);
+}
+
+#[typenaming::nameable_trait(crate_version = "1.2.3")]
+pub trait Source {
+    type Item;
+    fn next(&mut self) -> Option<Self::Item>;
+}
+
+#[test]
+fn trait_object() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(<dyn Plugin>::type_info());
+    assert_eq!("dyn Plugin", type_name.type_name());
+    assert_eq!(Some("typenaming"), type_name.crate_name().as_deref());
+    assert_eq!(Some("trait_object"), type_name.crate_module().as_deref());
+    assert_eq!(0, type_name.generics().len());
+    assert_eq!(
+        &Some(rustc_version::version().unwrap()),
+        type_name.rustc_version()
+    );
+}
+
+#[test]
+fn trait_object_auto_traits() {
+    use ::typenaming::TypeNameable;
+    assert_eq!(
+        "dyn Plugin + Send",
+        <dyn Plugin + Send>::type_info().type_name()
+    );
+    assert_eq!(
+        "dyn Plugin + Sync",
+        <dyn Plugin + Sync>::type_info().type_name()
+    );
+    assert_eq!(
+        "dyn Plugin + Send + Sync",
+        <dyn Plugin + Send + Sync>::type_info().type_name()
+    );
+}
+
+#[test]
+fn trait_object_generic() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(Box::<dyn Handler<String> + Send + Sync>::type_info());
+    assert_eq!("Box", type_name.type_name());
+    assert_eq!(Some("alloc"), type_name.crate_name().as_deref());
+    let type_name = &type_name.generics()[0];
+    assert_eq!("dyn Handler + Send + Sync", type_name.type_name());
+    assert_eq!(1, type_name.generics().len());
+    assert_eq!("String", type_name.generics()[0].type_name());
+}
+
+#[test]
+fn trait_object_associated_type() {
+    use ::typenaming::TypeNameable;
+    let type_name = dbg!(<dyn Source<Item = u8>>::type_info());
+    assert_eq!("dyn Source", type_name.type_name());
+    assert_eq!(
+        &Some(::typenaming::Version::new(1, 2, 3)),
+        type_name.crate_version()
+    );
+    assert_eq!(1, type_name.generics().len());
+    assert_eq!("u8", type_name.generics()[0].type_name());
+}
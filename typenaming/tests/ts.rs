@@ -0,0 +1,170 @@
+#![cfg(feature = "ts")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use typenaming::TypeNameable;
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct User {
+    id: u64,
+    name: String,
+    email: Option<String>,
+    tags: Vec<String>,
+    scores: HashMap<String, f64>,
+    role: Role,
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+enum Role {
+    Guest,
+    Member(u32),
+    Pair(u8, bool),
+    Admin { level: u8, teams: Vec<Team> },
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct Team(String);
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<Box<Page<T>>>,
+    total: usize,
+}
+
+#[test]
+fn structs_and_enums() {
+    let expected = [
+        "export interface User {",
+        "    id: number;",
+        "    name: string;",
+        "    email: string | null;",
+        "    tags: Array<string>;",
+        "    scores: Record<string, number>;",
+        "    role: Role;",
+        "}",
+        "",
+        "export type Role = \"Guest\" | { Member: number } | { Pair: [number, boolean] } | { Admin: { level: number; teams: Array<Team> } };",
+        "",
+        "export type Team = string;",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(expected, typenaming::ts::declarations::<User>());
+}
+
+#[test]
+fn generic_and_recursive() {
+    let expected = [
+        "export interface Page_User {",
+        "    items: Array<User>;",
+        "    next: Page_User | null;",
+        "    total: number;",
+        "}",
+        "",
+        "export interface User {",
+    ]
+    .join("\n");
+    let declarations = typenaming::ts::declarations::<Page<User>>();
+    assert!(declarations.starts_with(&expected), "{declarations}");
+    assert!(declarations.contains("export type Role = "));
+    assert_eq!(
+        1,
+        declarations.matches("export interface Page_User").count()
+    );
+}
+
+#[test]
+fn without_fields() {
+    assert_eq!(
+        "export type BTreeMap_u8_Vec_Option_bool = Record<number, Array<boolean | null>>;\n",
+        typenaming::ts::declarations::<BTreeMap<u8, Vec<Option<bool>>>>()
+    );
+}
+
+#[test]
+fn export() {
+    let path = std::env::temp_dir().join(format!("typenaming-ts-{}.d.ts", std::process::id()));
+    typenaming::ts::export::<Team>(&path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!("export type Team = string;\n", written);
+}
+
+mod api {
+    use typenaming::TypeNameable;
+
+    #[derive(TypeNameable)]
+    #[typenameable(fields)]
+    #[allow(dead_code)]
+    pub struct Error {
+        pub code: u16,
+    }
+}
+
+mod db {
+    use typenaming::TypeNameable;
+
+    #[derive(TypeNameable)]
+    #[typenameable(fields)]
+    #[allow(dead_code)]
+    pub struct Error(pub String);
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct Failure {
+    api: api::Error,
+    db: Option<db::Error>,
+}
+
+#[test]
+fn colliding_names() {
+    let expected = [
+        "export interface Failure {",
+        "    api: ts_api_Error;",
+        "    db: ts_db_Error | null;",
+        "}",
+        "",
+        "export interface ts_api_Error {",
+        "    code: number;",
+        "}",
+        "",
+        "export type ts_db_Error = string;",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(expected, typenaming::ts::declarations::<Failure>());
+}
+
+#[derive(TypeNameable)]
+#[typenameable(fields)]
+#[allow(dead_code)]
+struct Borrowed<'a> {
+    name: &'a str,
+    teams: &'a Vec<Team>,
+}
+
+#[test]
+fn borrowed() {
+    let expected = [
+        "export interface Borrowed {",
+        "    name: string;",
+        "    teams: Array<Team>;",
+        "}",
+        "",
+        "export type Team = string;",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(expected, typenaming::ts::declarations::<Borrowed>());
+}